@@ -0,0 +1,63 @@
+use anyhow::Context;
+use clap::Parser;
+use std::{collections::BTreeMap, io::Write, path::PathBuf};
+use utils::{
+    generate::{self, Generator},
+    AocResult,
+};
+
+/// Writes a random puzzle input for a day
+#[derive(Debug, Parser)]
+#[command(author, about, long_about = None)]
+struct Cli {
+    /// The day to write an input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Seed of the random numbers, the same seed gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big the input is, in the day's own items like elves or sensors
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+
+    /// File to write the input to instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+/// Input generator of every solved day
+fn generators() -> BTreeMap<u8, Generator> {
+    BTreeMap::from([
+        (1, day_1::generate as Generator),
+        (2, day_2::generate),
+        (3, day_3::generate),
+        (4, day_4::generate),
+        (5, day_5::generate),
+        (6, day_6::generate),
+        (7, day_7::generate),
+        (8, day_8::generate),
+        (9, day_9::generate),
+        (10, day_10::generate),
+        (11, day_11::generate),
+        (12, day_12::generate),
+        (13, day_13::generate),
+        (14, day_14::generate),
+        (15, day_15::generate),
+    ])
+}
+
+fn main() -> AocResult<()> {
+    let cli = Cli::parse();
+    let generate = generators()
+        .remove(&cli.day)
+        .with_context(|| format!("Day {} has no input generator", cli.day))?;
+    let input = generate(&mut generate::rng(cli.seed), cli.size);
+    match &cli.output {
+        Some(path) => std::fs::write(path, input)
+            .with_context(|| format!("Unable to write {}", path.display()))?,
+        None => std::io::stdout().write_all(input.as_bytes())?,
+    }
+    Ok(())
+}
@@ -0,0 +1,345 @@
+use anyhow::Context;
+use clap::Parser;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use utils::{
+    answers::{Answers, Mismatch, ANSWERS_FILE},
+    memory::{Bytes, Memory},
+    report::{PartReport, Report},
+    solution::{DaySet, Registry},
+    timing::Human,
+    Answer, AocResult, OutputFormat, RunOptions,
+};
+
+#[cfg(feature = "mem")]
+#[global_allocator]
+static ALLOCATOR: utils::memory::CountingAlloc = utils::memory::CountingAlloc;
+
+/// Runs the solutions of many days in one go
+#[derive(Debug, Parser)]
+#[command(author, about, long_about = None)]
+struct Cli {
+    /// Days to run, like `3`, `1..=15` or `1,4..7`, every solved day by default
+    days: Option<DaySet>,
+
+    /// Folder holding the `day-N/input.txt` files
+    #[arg(long, default_value = ".")]
+    inputs: PathBuf,
+
+    #[command(flatten)]
+    run: RunOptions,
+
+    /// Compare the answers with each day's `answers.toml` and fail on a mismatch
+    #[arg(long)]
+    check: bool,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t)]
+    format: OutputFormat,
+}
+
+/// Every solved day
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register(day_1::solution())
+        .register(day_2::solution())
+        .register(day_3::solution())
+        .register(day_4::solution())
+        .register(day_5::solution())
+        .register(day_6::solution())
+        .register(day_7::solution())
+        .register(day_8::solution())
+        .register(day_9::solution())
+        .register(day_10::solution())
+        .register(day_11::solution())
+        .register(day_12::solution())
+        .register(day_13::solution())
+        .register(day_14::solution())
+        .register(day_15::solution());
+    registry
+}
+
+/// Result of comparing a day with its `answers.toml`
+enum Check {
+    NoAnswers,
+    Passed,
+    Failed(Vec<Mismatch>),
+}
+
+impl Check {
+    fn new(day_dir: &Path, report: &Report) -> AocResult<Self> {
+        if !day_dir.join(ANSWERS_FILE).exists() {
+            return Ok(Self::NoAnswers);
+        }
+        let mismatches = Answers::load(day_dir)?.check(report);
+        Ok(if mismatches.is_empty() {
+            Self::Passed
+        } else {
+            Self::Failed(mismatches)
+        })
+    }
+
+    const fn cell(&self) -> &'static str {
+        match self {
+            Self::NoAnswers => "-",
+            Self::Passed => "ok",
+            Self::Failed(_) => "FAIL",
+        }
+    }
+}
+
+/// Longest answer shown in the summary table
+const MAX_ANSWER_WIDTH: usize = 24;
+
+fn answer_cell(report: &Report, part: u8) -> String {
+    let cell = match report.parts.iter().find(|p| p.part == part) {
+        Some(PartReport {
+            answer: Some(Answer::Picture(_)),
+            ..
+        }) => "(picture)".to_string(),
+        Some(PartReport {
+            answer: Some(answer),
+            ..
+        }) => answer.to_string(),
+        Some(PartReport { error: Some(_), .. }) => "error".to_string(),
+        _ if report.error.is_some() => "error".to_string(),
+        _ => "-".to_string(),
+    };
+    if cell.chars().count() > MAX_ANSWER_WIDTH {
+        let cut: String = cell.chars().take(MAX_ANSWER_WIDTH - 1).collect();
+        format!("{cut}…")
+    } else {
+        cell
+    }
+}
+
+/// Sum of the median times of the phases matching `phase`
+fn phase_time(report: &Report, phase: impl Fn(&str) -> bool) -> Duration {
+    report
+        .timings
+        .iter()
+        .filter(|t| phase(&t.phase))
+        .map(|t| t.timing.median())
+        .sum()
+}
+
+/// Memory of two phases or reports together, with the highest of their peaks
+fn combine(total: Memory, memory: Memory) -> Memory {
+    Memory {
+        allocations: total.allocations + memory.allocations,
+        bytes: total.bytes + memory.bytes,
+        peak_heap_bytes: total.peak_heap_bytes.max(memory.peak_heap_bytes),
+        peak_rss_bytes: total.peak_rss_bytes.max(memory.peak_rss_bytes),
+    }
+}
+
+/// Peak resident set size of a table row, `-` when the system doesn't tell it
+fn rss_cell(memory: Memory) -> String {
+    memory
+        .peak_rss_bytes
+        .map_or_else(|| "-".to_string(), |rss| Bytes(rss).to_string())
+}
+
+/// Allocations of every phase of a report, with the highest peak of them
+///
+/// `None` when the report has no memory measurements.
+fn report_memory(report: &Report) -> Option<Memory> {
+    report
+        .timings
+        .iter()
+        .filter_map(|t| t.memory)
+        .reduce(combine)
+}
+
+/// Formats the reports as a table with one row per day
+///
+/// Columns with the allocations, heap peak and peak RSS are added when the
+/// reports were measured with `--mem`, and a column with the outcome of
+/// `--check` when `checks` has one entry per report.
+fn table(reports: &[Report], checks: &[Check]) -> String {
+    let time = |d: Duration| Human(d).to_string();
+    let mut header = vec![
+        "Day", "Part 1", "Part 2", "Parse", "Time 1", "Time 2", "Total",
+    ];
+    let with_memory = reports.iter().any(|r| report_memory(r).is_some());
+    if with_memory {
+        header.extend(["Allocs", "Peak heap", "Peak RSS"]);
+    }
+    let with_checks = !checks.is_empty();
+    if with_checks {
+        header.push("Check");
+    }
+    let mut rows: Vec<Vec<String>> = reports
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let mut row = vec![
+                r.day.to_string(),
+                answer_cell(r, 1),
+                answer_cell(r, 2),
+                time(phase_time(r, |p| p.starts_with("parse"))),
+                time(phase_time(r, |p| p == "part 1")),
+                time(phase_time(r, |p| p == "part 2")),
+                time(phase_time(r, |_| true)),
+            ];
+            if with_memory {
+                let memory = report_memory(r).unwrap_or_default();
+                row.push(memory.allocations.to_string());
+                row.push(Bytes(memory.peak_heap_bytes).to_string());
+                row.push(rss_cell(memory));
+            }
+            if let Some(check) = checks.get(i) {
+                row.push(check.cell().to_string());
+            }
+            row
+        })
+        .collect();
+    let total: Duration = reports.iter().map(|r| phase_time(r, |_| true)).sum();
+    let mut total_row = vec![String::new(); header.len()];
+    total_row[0] = "Total".to_string();
+    total_row[6] = time(total);
+    if with_memory {
+        let memory = reports
+            .iter()
+            .filter_map(report_memory)
+            .fold(Memory::default(), combine);
+        total_row[7] = memory.allocations.to_string();
+        total_row[8] = Bytes(memory.peak_heap_bytes).to_string();
+        total_row[9] = rss_cell(memory);
+    }
+    rows.push(total_row);
+
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+    let separator = widths
+        .iter()
+        .map(|&width| "-".repeat(width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+
+    let header: Vec<String> = header.into_iter().map(String::from).collect();
+    let mut lines = vec![line(&header), separator.clone()];
+    let (total, days) = rows.split_last().expect("rows always has the total");
+    lines.extend(days.iter().map(|row| line(row)));
+    lines.push(separator);
+    lines.push(line(total));
+    lines.join("\n")
+}
+
+fn main() -> AocResult<()> {
+    let cli = Cli::parse();
+    let registry = registry();
+    let days = match &cli.days {
+        Some(days) => {
+            for day in days.iter().filter(|&d| registry.get(d).is_none()) {
+                eprintln!("Warning: day {day} has no solution");
+            }
+            days.clone()
+        }
+        None => DaySet::all(),
+    };
+
+    let day_dir = |day| cli.inputs.join(format!("day-{day}"));
+    let reports: Vec<Report> = days
+        .iter()
+        .filter_map(|day| registry.get(day))
+        .map(|solution| {
+            let path = day_dir(solution.day()).join("input.txt");
+            match std::fs::read_to_string(&path)
+                .with_context(|| format!("Unable to read {}", path.display()))
+            {
+                Ok(input) => solution.run(&input, &cli.run),
+                Err(e) => {
+                    let mut report = Report::new(solution.day());
+                    report.fail(e);
+                    report
+                }
+            }
+        })
+        .collect();
+    let checks = if cli.check {
+        reports
+            .iter()
+            .map(|r| Check::new(&day_dir(r.day), r))
+            .collect::<AocResult<Vec<_>>>()?
+    } else {
+        Vec::new()
+    };
+
+    match cli.format {
+        OutputFormat::Text => {
+            println!("{}", table(&reports, &checks));
+            for report in &reports {
+                if let Some(e) = &report.error {
+                    eprintln!("Day {}: {e:#}", report.day);
+                }
+                for part in &report.parts {
+                    if let Some(e) = &part.error {
+                        eprintln!("Day {} part {}: {e:#}", report.day, part.part);
+                    }
+                }
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+    }
+
+    let mismatches: Vec<&Mismatch> = checks
+        .iter()
+        .flat_map(|c| match c {
+            Check::Failed(mismatches) => mismatches.as_slice(),
+            _ => &[],
+        })
+        .collect();
+    for mismatch in &mismatches {
+        eprintln!("{mismatch}");
+    }
+
+    let failed = reports.iter().filter(|r| r.has_error()).count();
+    anyhow::ensure!(failed == 0, "{failed} of {} days failed", reports.len());
+    anyhow::ensure!(
+        mismatches.is_empty(),
+        "{} answers do not match",
+        mismatches.len()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_table() {
+    use utils::timing::Timing;
+
+    let mut report = Report::new(10);
+    report.part(1, Ok(13_140));
+    report.part(2, Ok("##..##..##..##..##..##..##..##.."));
+    report.time("parse", Timing::new(vec![Duration::from_micros(2)]));
+    report.time("part 1", Timing::new(vec![Duration::from_micros(3)]));
+    let mut failed = Report::new(11);
+    failed.fail(anyhow::anyhow!("no input"));
+    assert_eq!(
+        table(&[report, failed], &[Check::Passed, Check::NoAnswers]),
+        "\
+Day   | Part 1 | Part 2                   | Parse  | Time 1 | Time 2 | Total  | Check
+------+--------+--------------------------+--------+--------+--------+--------+------
+10    | 13140  | ##..##..##..##..##..##.… | 2.00µs | 3.00µs | 0ns    | 5.00µs | ok
+11    | error  | error                    | 0ns    | 0ns    | 0ns    | 0ns    | -
+------+--------+--------------------------+--------+--------+--------+--------+------
+Total |        |                          |        |        |        | 5.00µs |"
+    );
+}
@@ -0,0 +1,31 @@
+use bench::{bench_day, input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::path::Path;
+
+/// Benchmarks every solved day
+fn days(c: &mut Criterion) {
+    let root = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    bench_day(c, &day_1::solution(), &input(root, 1, day_1::generate));
+    bench_day(c, &day_2::solution(), &input(root, 2, day_2::generate));
+    bench_day(c, &day_3::solution(), &input(root, 3, day_3::generate));
+    bench_day(c, &day_4::solution(), &input(root, 4, day_4::generate));
+    bench_day(c, &day_5::solution(), &input(root, 5, day_5::generate));
+    bench_day(c, &day_6::solution(), &input(root, 6, day_6::generate));
+    bench_day(c, &day_7::solution(), &input(root, 7, day_7::generate));
+    bench_day(c, &day_8::solution(), &input(root, 8, day_8::generate));
+    bench_day(c, &day_9::solution(), &input(root, 9, day_9::generate));
+    bench_day(c, &day_10::solution(), &input(root, 10, day_10::generate));
+    bench_day(c, &day_11::solution(), &input(root, 11, day_11::generate));
+    bench_day(c, &day_12::solution(), &input(root, 12, day_12::generate));
+    bench_day(c, &day_13::solution(), &input(root, 13, day_13::generate));
+    bench_day(c, &day_14::solution(), &input(root, 14, day_14::generate));
+    bench_day(c, &day_15::solution(), &input(root, 15, day_15::generate));
+}
+
+criterion_group! {
+    name = benches;
+    // Some parts take seconds, so keep to the fewest samples criterion allows
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+use criterion::{black_box, Criterion};
+use std::path::Path;
+use utils::{
+    generate::{self, Generator},
+    Params, RunOptions, Solution,
+};
+
+/// Seed of the generated inputs, fixed so every run measures the same input
+pub const SEED: u64 = 0;
+
+/// Size of the generated inputs unless `AOC_BENCH_SIZE` says otherwise
+pub const SIZE: usize = 100;
+
+/// Puzzle input to benchmark a day with
+///
+/// Uses `day-N/input.txt` under `root` when it's there, so the numbers
+/// match the real puzzle, and a generated input otherwise. Setting
+/// `AOC_BENCH_SIZE` always generates one, of that size.
+///
+/// # Panics
+///
+/// Panics if `AOC_BENCH_SIZE` isn't a number
+#[must_use]
+pub fn input(root: &Path, day: u8, generate: Generator) -> String {
+    let size = std::env::var("AOC_BENCH_SIZE")
+        .ok()
+        .map(|size| size.parse().expect("AOC_BENCH_SIZE is a number"));
+    let path = root.join(format!("day-{day}")).join("input.txt");
+    if size.is_none() {
+        if let Ok(input) = std::fs::read_to_string(&path) {
+            eprintln!("day {day}: {}", path.display());
+            return input;
+        }
+    }
+    let size = size.unwrap_or(SIZE);
+    eprintln!("day {day}: generated with seed {SEED} and size {size}");
+    generate(&mut generate::rng(SEED), size)
+}
+
+/// Benchmarks parsing and both parts of a day, as the group `day-N`
+///
+/// Every phase is measured on its own: the parts get an input parsed
+/// beforehand and run with the default parameters.
+///
+/// # Panics
+///
+/// Panics if the input doesn't parse
+pub fn bench_day<S: Solution>(c: &mut Criterion, solution: &S, input: &str) {
+    let day = solution.day();
+    let options = RunOptions::default();
+    let params = Params::new::<&str, &str>(solution.params(), &[]).expect("no overrides");
+    let finish = |parsed| {
+        options
+            .finish(input, parsed)
+            .unwrap_or_else(|e| panic!("day {day} can't parse its input: {e:#}"))
+    };
+    let parsed = finish(solution.parse(input));
+
+    let mut group = c.benchmark_group(format!("day-{day}"));
+    group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(input))));
+    group.bench_function("part1", |b| {
+        b.iter(|| solution.part1(black_box(&parsed), &params));
+    });
+    let parsed2;
+    let parsed2 = if let Some(shared) = solution.shared() {
+        shared(&parsed)
+    } else {
+        group.bench_function("parse2", |b| b.iter(|| solution.parse2(black_box(input))));
+        parsed2 = options
+            .finish(input, solution.parse2(input))
+            .unwrap_or_else(|e| panic!("day {day} can't parse its input for part 2: {e:#}"));
+        &parsed2
+    };
+    group.bench_function("part2", |b| {
+        b.iter(|| solution.part2(black_box(parsed2), &params));
+    });
+    group.finish();
+}
+
+#[test]
+fn test_input() {
+    fn generate(_: &mut generate::StdRng, size: usize) -> String {
+        "1\n".repeat(size)
+    }
+    let root = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("day-3")).unwrap();
+    std::fs::write(root.join("day-3").join("input.txt"), "real\n").unwrap();
+    let real = input(&root, 3, generate);
+    let generated = input(&root, 4, generate);
+    std::fs::remove_dir_all(&root).unwrap();
+
+    if std::env::var_os("AOC_BENCH_SIZE").is_none() {
+        assert_eq!(real, "real\n");
+        assert_eq!(generated, "1\n".repeat(SIZE));
+    }
+}
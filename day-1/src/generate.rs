@@ -0,0 +1,20 @@
+use utils::generate::{Rng, StdRng};
+
+/// Writes `size` elves carrying 1 to 10 snacks each
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let snacks: Vec<String> = (0..rng.gen_range(1..=10))
+                .map(|_| rng.gen_range(1_000..=60_000).to_string())
+                .collect();
+            snacks.join("\n")
+        })
+        .collect();
+    elves.join("\n\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,87 @@
+use std::cmp::Reverse;
+
+use itertools::Itertools;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+#[derive(Debug, PartialEq, Eq)]
+/// The calories of the food every elf carries
+pub struct InputData {
+    pub elfs: Vec<Vec<u64>>,
+}
+
+/// Parses blocks of calories, one block per elf
+///
+/// # Errors
+///
+/// Fails if a line isn't a number
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::combinator::map;
+    use utils::parsers::{blocks, lines, unsigned};
+    let elfs = blocks(lines(unsigned));
+    let mut parse = map(elfs, |elfs| InputData { elfs });
+    parse(input)
+}
+
+pub const PARAMS: &[Param] = &[Param::new("top", "3", "number of elves to total in part 2")];
+
+/// Calories carried by the elf carrying the most
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData, _params: &Params) -> AocResult<u64> {
+    Ok(input
+        .elfs
+        .iter()
+        // Sum all values
+        .map(|v| v.iter().sum())
+        // Find the max value
+        .max()
+        .unwrap_or_default())
+}
+
+/// Calories carried by the `top` elves carrying the most
+///
+/// # Errors
+///
+/// Fails if the `top` parameter isn't a number
+pub fn part2(input: &InputData, params: &Params) -> AocResult<u64> {
+    let top = params.get("top")?;
+    Ok(input
+        .elfs
+        .iter()
+        // Sum all values
+        .map(|v| v.iter().sum::<u64>())
+        // Sort in reverse order
+        .sorted_by_key(|&s| Reverse(s))
+        .take(top)
+        .sum())
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::with_params(1, parse, part1, part2, PARAMS)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+    assert_parser!(
+        parse,
+        input,
+        InputData {
+            elfs: vec![
+                vec![1000, 2000, 3000],
+                vec![4000],
+                vec![5000, 6000],
+                vec![7000, 8000, 9000],
+                vec![10_000],
+            ]
+        }
+    );
+}
@@ -0,0 +1,31 @@
+use utils::generate::{Rng, StdRng};
+
+const CYCLES: usize = 240;
+
+/// Writes a program of at least `size` instructions
+///
+/// The program always runs for the 240 cycles the CRT needs, and keeps
+/// the sprite on the screen.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut instructions = Vec::new();
+    let mut cycles = 0;
+    let mut x: i64 = 1;
+    while cycles < CYCLES || instructions.len() < size {
+        if rng.gen_bool(0.3) {
+            instructions.push("noop".to_string());
+            cycles += 1;
+        } else {
+            let to = rng.gen_range(-1..=40);
+            instructions.push(format!("addx {}", to - x));
+            x = to;
+            cycles += 2;
+        }
+    }
+    instructions.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,241 @@
+use itertools::Itertools;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// An instruction of the CPU
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Instruction {
+    NoOp,
+    AddX(i64),
+}
+
+/// The program the CPU runs
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    pub instructions: Vec<Instruction>,
+}
+
+/// Parses one `noop` or `addx N` per line
+///
+/// # Errors
+///
+/// Fails if a line isn't an instruction
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{i64, line_ending},
+        combinator::map,
+        multi::separated_list1,
+        sequence::preceded,
+    };
+    let noop = map(tag("noop"), |_| Instruction::NoOp);
+    let addx = map(preceded(tag("addx "), i64), Instruction::AddX);
+    let mut parser = map(
+        separated_list1(line_ending, alt((noop, addx))),
+        |instructions| InputData { instructions },
+    );
+    parser(input)
+}
+
+/// Sum of the signal strengths during the 20th, 60th, 100th... cycles
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData) -> AocResult<i64> {
+    let mut strengths: Vec<i64> = Vec::new();
+    let mut cycle_count = 0;
+    let mut x = 1;
+
+    for &instruction in &input.instructions {
+        match instruction {
+            Instruction::NoOp => {
+                cycle_count += 1;
+                strengths.push(cycle_count * x);
+            }
+            Instruction::AddX(n) => {
+                cycle_count += 1;
+                strengths.push(cycle_count * x);
+                cycle_count += 1;
+                strengths.push(cycle_count * x);
+                x += n;
+            }
+        }
+    }
+    Ok(strengths
+        .iter()
+        .skip(19)
+        .step_by(40)
+        .sorted_by_key(|i| std::cmp::Reverse(*i))
+        .take(6)
+        .sum())
+}
+
+/// Picture drawn on the CRT, one string per line
+#[derive(Debug, PartialEq, Eq)]
+pub struct Crt(pub Vec<String>);
+
+impl AocAnswer for Crt {
+    /// The letters on the screen, or the picture if they can't be read
+    fn answer(&self) -> Answer {
+        ocr::recognize(&self.0).map_or_else(|| self.0.answer(), Answer::Text)
+    }
+
+    fn details(&self) -> Option<String> {
+        Some(self.0.join("\n"))
+    }
+}
+
+/// Value of the X register during each cycle
+fn register(input: &InputData) -> Vec<i64> {
+    let mut pixels: Vec<i64> = Vec::new();
+    let mut x = 1;
+
+    for &instruction in &input.instructions {
+        match instruction {
+            Instruction::NoOp => {
+                pixels.push(x);
+            }
+            Instruction::AddX(n) => {
+                pixels.push(x);
+                pixels.push(x);
+                x += n;
+            }
+        }
+    }
+    pixels
+}
+
+/// The picture the program draws on the CRT
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData) -> AocResult<Crt> {
+    let pixels = register(input);
+    let mut crt: Vec<String> = vec![];
+    let mut crt_line = String::new();
+
+    for line in 0..6 {
+        for pixel in 0..40 {
+            if let Some(x) = pixels.get((line * 40) + pixel) {
+                if (x - 1..=x + 1).contains(&(pixel as i64)) {
+                    crt_line += "#";
+                } else {
+                    crt_line += " ";
+                }
+            } else {
+                crt_line += " ";
+            }
+        }
+        crt.push(crt_line.clone());
+        crt_line.clear();
+    }
+    Ok(Crt(crt))
+}
+
+/// The CRT drawing one pixel per cycle, with the sprite under it
+struct CrtViz {
+    register: Vec<i64>,
+    cycle: usize,
+}
+
+impl CrtViz {
+    const WIDTH: usize = 40;
+    const HEIGHT: usize = 6;
+}
+
+impl viz::Visualize for CrtViz {
+    fn frame(&self) -> viz::Frame {
+        let x = self.register.get(self.cycle).copied().unwrap_or_default();
+        let mut frame = viz::Frame::blank(Self::WIDTH, Self::HEIGHT + 2).with_caption(format!(
+            "cycle {}, X = {x}, signal strength {}",
+            self.cycle + 1,
+            (self.cycle as i64 + 1) * x
+        ));
+        for (pixel, sprite) in self.register.iter().enumerate().take(self.cycle) {
+            let (column, row) = (pixel % Self::WIDTH, pixel / Self::WIDTH);
+            if (sprite - 1..=sprite + 1).contains(&(column as i64)) {
+                frame.paint(column, row, '#', viz::Color::Green);
+            } else {
+                frame.set(column, row, '.');
+            }
+        }
+        for column in x - 1..=x + 1 {
+            if let Ok(column) = usize::try_from(column) {
+                frame.paint(column, Self::HEIGHT, '#', viz::Color::Yellow);
+            }
+        }
+        frame.paint(
+            self.cycle % Self::WIDTH,
+            Self::HEIGHT + 1,
+            '^',
+            viz::Color::Red,
+        );
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        if self.cycle + 1 >= self.register.len().min(Self::WIDTH * Self::HEIGHT) {
+            return false;
+        }
+        self.cycle += 1;
+        true
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn visualize(input: &InputData, _part: u8, _params: &Params) -> AocResult<Box<dyn viz::Visualize>> {
+    Ok(Box::new(CrtViz {
+        register: register(input),
+        cycle: 0,
+    }))
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(10, parse, part1, part2).with_visualize(visualize)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    use Instruction::*;
+    let input1 = "noop\naddx 3\naddx -5";
+    assert_parser!(
+        parse,
+        input1,
+        InputData {
+            instructions: vec![NoOp, AddX(3), AddX(-5)]
+        }
+    );
+    let letters = [
+        "#  # #### ###  ",
+        "#  # #    #  # ",
+        "#### ###  #  # ",
+        "#  # #    ###  ",
+        "#  # #    #    ",
+        "#  # #### #    ",
+    ];
+    let crt = Crt(letters.map(String::from).to_vec());
+    assert_eq!(crt.answer(), Answer::Text("HEP".to_string()));
+    assert_eq!(crt.details(), Some(letters.join("\n")));
+}
+
+#[test]
+fn test_visualize() {
+    let (_, input) = parse("noop\naddx 3\naddx -5\nnoop").unwrap();
+    let mut crt = CrtViz {
+        register: register(&input),
+        cycle: 0,
+    };
+    let frames = viz::frames(&mut crt, usize::MAX);
+    assert_eq!(frames.len(), 6);
+    let last = frames[5].to_string();
+    let lines: Vec<&str> = last.lines().map(str::trim_end).collect();
+    assert_eq!(lines[0], "#####");
+    assert_eq!(lines[6], "#");
+    assert_eq!(lines[7], "     ^");
+    assert_eq!(frames[5].caption, "cycle 6, X = -1, signal strength -6");
+}
@@ -0,0 +1,52 @@
+use utils::generate::{Rng, SliceRandom, StdRng};
+
+/// Divisors of the tests, kept small so worries times themselves fit in a `u64`
+const DIVISORS: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+/// Writes `size` items held by two to eight monkeys
+///
+/// Like the puzzle's, every monkey tests a different prime and throws to
+/// other monkeys only.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let monkeys = size.clamp(2, DIVISORS.len());
+    let mut items = vec![1; monkeys];
+    for _ in monkeys..size {
+        items[rng.gen_range(0..monkeys)] += 1;
+    }
+    let mut divisors = DIVISORS;
+    divisors.shuffle(rng);
+
+    let blocks: Vec<String> = (0..monkeys)
+        .map(|monkey| {
+            let worries: Vec<String> = (0..items[monkey])
+                .map(|_| rng.gen_range(50..=99).to_string())
+                .collect();
+            let operation = match rng.gen_range(0..6) {
+                0 => "old * old".to_string(),
+                1 | 2 => format!("old * {}", rng.gen_range(2..=19)),
+                _ => format!("old + {}", rng.gen_range(1..=8)),
+            };
+            let mut others: Vec<usize> = (0..monkeys).filter(|&m| m != monkey).collect();
+            others.shuffle(rng);
+            format!(
+                "Monkey {monkey}:\n  \
+                 Starting items: {}\n  \
+                 Operation: new = {operation}\n  \
+                 Test: divisible by {}\n    \
+                 If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                worries.join(", "),
+                divisors[monkey],
+                others[0],
+                others[1 % others.len()],
+            )
+        })
+        .collect();
+    blocks.join("\n")
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,418 @@
+use std::{collections::VecDeque, num::NonZeroU64};
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// A side of an operation, the old worry level or a number
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Operand {
+    Old,
+    Const(u64),
+}
+
+impl Operand {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        use nom::{branch::alt, bytes::complete::tag, character::complete::u64, combinator::map};
+        let old = map(tag("old"), |_| Self::Old);
+        let const_ = map(u64, Self::Const);
+        alt((old, const_))(input)
+    }
+
+    const fn value(&self, n: u64) -> u64 {
+        match self {
+            Self::Const(i) => *i,
+            Self::Old => n,
+        }
+    }
+}
+
+/// How an operation combines its sides
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        use nom::{branch::alt, bytes::complete::tag, combinator::map};
+        let add = map(tag(" + "), |_| Self::Add);
+        let mul = map(tag(" * "), |_| Self::Multiply);
+        alt((add, mul))(input)
+    }
+
+    const fn operate(self, i: u64, j: u64) -> u64 {
+        match self {
+            Self::Add => i + j,
+            Self::Multiply => i * j,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Relief(u64);
+
+impl Relief {
+    const fn relieve(self, item: Item) -> Item {
+        Item(item.0 / self.0)
+    }
+}
+
+/// How a monkey changes the worry level of the item it inspects
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Operation {
+    pub operator: Operator,
+    pub operand1: Operand,
+    pub operand2: Operand,
+}
+
+impl Operation {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        use nom::{combinator::map, sequence::tuple};
+        map(
+            tuple((Operand::parse, Operator::parse, Operand::parse)),
+            |(operand1, operator, operand2)| Self {
+                operator,
+                operand1,
+                operand2,
+            },
+        )(input)
+    }
+
+    const fn operate(&self, n: u64) -> u64 {
+        self.operator
+            .operate(self.operand1.value(n), self.operand2.value(n))
+    }
+}
+
+/// An item, by its worry level
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Item(pub u64);
+
+impl Item {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        use nom::combinator::map;
+        use utils::parsers::unsigned;
+        map(unsigned, Self)(input)
+    }
+
+    const fn inspect(self, op: &Operation) -> Self {
+        Self(op.operate(self.0))
+    }
+
+    const fn reduce(self, amount: u64) -> Self {
+        Self(self.0 % amount)
+    }
+}
+
+/// A monkey and the items it holds
+#[derive(Debug, PartialEq, Clone)]
+pub struct Monkey {
+    pub items: VecDeque<Item>,
+    pub operation: Operation,
+    /// Divisor the monkey tests worry levels with
+    pub test: u64,
+    /// Monkey the item goes to when the test passes
+    pub true_test: usize,
+    /// Monkey the item goes to when the test fails
+    pub false_test: usize,
+}
+
+impl Monkey {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        use nom::{
+            bytes::complete::tag,
+            character::complete::line_ending,
+            combinator::map,
+            error::context,
+            sequence::{delimited, pair, preceded, terminated, tuple},
+            ToUsize,
+        };
+        use utils::parsers::{comma_list, header, unsigned};
+        let heading = context(
+            "heading",
+            delimited(tag("Monkey "), unsigned::<u64>, pair(tag(":"), line_ending)),
+        );
+        let items = map(
+            header("Starting items", comma_list(Item::parse)),
+            VecDeque::from,
+        );
+        let items = terminated(items, line_ending);
+        let operation = header("Operation", preceded(tag("new = "), Operation::parse));
+        let operation = terminated(operation, line_ending);
+        let test = header("Test", preceded(tag("divisible by "), unsigned));
+        let test = terminated(test, line_ending);
+        let throw_to = || preceded(tag("throw to monkey "), unsigned::<u64>);
+        let true_test = terminated(header("If true", throw_to()), line_ending);
+        let false_test = header("If false", throw_to());
+        let monkey = tuple((heading, items, operation, test, true_test, false_test));
+        map(
+            context("monkey", monkey),
+            |(_, items, operation, test, true_test, false_test)| Self {
+                items,
+                operation,
+                test,
+                true_test: true_test.to_usize(),
+                false_test: false_test.to_usize(),
+            },
+        )(input)
+    }
+
+    fn catch(&mut self, n: Item) {
+        self.items.push_back(n);
+    }
+
+    const fn throw(&self, item: Item) -> usize {
+        if item.0.is_multiple_of(self.test) {
+            self.true_test
+        } else {
+            self.false_test
+        }
+    }
+
+    fn inspect(&mut self, relief: Relief) -> Option<Item> {
+        let item = self.items.pop_front()?;
+        let item = item.inspect(&self.operation);
+        Some(relief.relieve(item))
+    }
+}
+
+/// Every monkey, in the order they take their turns
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    pub monkeys: Vec<Monkey>,
+}
+
+/// Parses the notes on every monkey, separated by blank lines
+///
+/// # Errors
+///
+/// Fails if a monkey's notes are missing a line or have a malformed one
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::combinator::map;
+    use utils::parsers::blocks;
+    map(blocks(Monkey::parse), |monkeys| InputData { monkeys })(input)
+}
+
+pub const PARAMS: &[Param] = &[
+    Param::new("rounds1", "20", "rounds to play in part 1"),
+    Param::new(
+        "relief",
+        "3",
+        "worry divisor after each inspection in part 1",
+    ),
+    Param::new("rounds2", "10000", "rounds to play in part 2"),
+];
+
+fn monkey_business(input: &InputData, rounds: usize, relief: Relief) -> usize {
+    let mut monkeys: Vec<Monkey> = input.monkeys.clone();
+    let mut inspected: Vec<usize> = vec![0; monkeys.len()];
+    let item_reduce: u64 = input.monkeys.iter().map(|m| m.test).product();
+
+    for _round in 0..rounds {
+        for turn in 0..monkeys.len() {
+            while let Some(item) = monkeys[turn].inspect(relief) {
+                let recipient = monkeys[turn].throw(item);
+                monkeys[recipient].catch(item.reduce(item_reduce));
+                inspected[turn] += 1;
+            }
+        }
+    }
+    inspected.sort_by_key(|i| std::cmp::Reverse(*i));
+    inspected.iter().take(2).product()
+}
+
+/// Where an item is at the start of a round, and how often each monkey
+/// inspected it so far
+#[derive(Debug, Clone)]
+struct Trip {
+    monkey: usize,
+    item: Item,
+    inspected: Vec<usize>,
+}
+
+impl Trip {
+    /// Follows the item through a round, where it's inspected again
+    /// whenever it's thrown to a monkey that didn't have its turn yet
+    fn round(&self, monkeys: &[Monkey], item_reduce: u64) -> Self {
+        let mut trip = self.clone();
+        loop {
+            let monkey = &monkeys[trip.monkey];
+            let item = trip.item.inspect(&monkey.operation).reduce(item_reduce);
+            trip.inspected[trip.monkey] += 1;
+            let recipient = monkey.throw(item);
+            let next_round = recipient < trip.monkey;
+            trip.monkey = recipient;
+            trip.item = item;
+            if next_round {
+                return trip;
+            }
+        }
+    }
+}
+
+/// Monkey business without relief, projected from the rounds before each
+/// item's trip around the monkeys starts repeating
+///
+/// Items never meet, so every item is followed on its own until it's back
+/// at a monkey with a worry level it had before, and its inspections are
+/// extrapolated from there.
+fn projected_business(input: &InputData, rounds: usize) -> usize {
+    let monkeys = &input.monkeys;
+    let item_reduce: u64 = monkeys.iter().map(|m| m.test).product();
+    let mut inspected: Vec<usize> = vec![0; monkeys.len()];
+
+    for (monkey, start) in monkeys.iter().enumerate() {
+        for &item in &start.items {
+            let trip = Trip {
+                monkey,
+                item,
+                inspected: vec![0; monkeys.len()],
+            };
+            let timeline = cycle::simulate(
+                trip,
+                rounds,
+                |trip| trip.round(monkeys, item_reduce),
+                |trip| (trip.monkey, trip.item.0),
+            );
+            for (i, count) in inspected.iter_mut().enumerate() {
+                *count += timeline.extrapolate(rounds, |trip| trip.inspected[i]);
+            }
+        }
+    }
+    inspected.sort_by_key(|i| std::cmp::Reverse(*i));
+    inspected.iter().take(2).product()
+}
+
+/// Monkey business after `rounds1` rounds with relief
+///
+/// # Errors
+///
+/// Fails if the `rounds1` or `relief` parameters aren't valid
+pub fn part1(input: &InputData, params: &Params) -> AocResult<usize> {
+    let relief: NonZeroU64 = params.get("relief")?;
+    Ok(monkey_business(
+        input,
+        params.get("rounds1")?,
+        Relief(relief.get()),
+    ))
+}
+
+/// Monkey business after `rounds2` rounds without relief
+///
+/// # Errors
+///
+/// Fails if the `rounds2` parameter isn't a number
+pub fn part2(input: &InputData, params: &Params) -> AocResult<usize> {
+    Ok(projected_business(input, params.get("rounds2")?))
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::with_params(11, parse, part1, part2, PARAMS)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+    assert_parser!(
+        parse,
+        input,
+        InputData {
+            monkeys: vec![
+                Monkey {
+                    items: vec![Item(79), Item(98)].into(),
+                    operation: Operation {
+                        operator: Operator::Multiply,
+                        operand1: Operand::Old,
+                        operand2: Operand::Const(19),
+                    },
+                    test: 23,
+                    true_test: 2,
+                    false_test: 3,
+                },
+                Monkey {
+                    items: vec![Item(54), Item(65), Item(75), Item(74)].into(),
+                    operation: Operation {
+                        operator: Operator::Add,
+                        operand1: Operand::Old,
+                        operand2: Operand::Const(6),
+                    },
+                    test: 19,
+                    true_test: 2,
+                    false_test: 0,
+                },
+                Monkey {
+                    items: vec![Item(79), Item(60), Item(97)].into(),
+                    operation: Operation {
+                        operator: Operator::Multiply,
+                        operand1: Operand::Old,
+                        operand2: Operand::Old,
+                    },
+                    test: 13,
+                    true_test: 1,
+                    false_test: 3,
+                },
+                Monkey {
+                    items: vec![Item(74)].into(),
+                    operation: Operation {
+                        operator: Operator::Add,
+                        operand1: Operand::Old,
+                        operand2: Operand::Const(3),
+                    },
+                    test: 17,
+                    true_test: 0,
+                    false_test: 1,
+                }
+            ]
+        }
+    );
+}
+
+#[test]
+fn test_projection() {
+    let (_, input) = parse(include_str!("../examples/1.txt")).unwrap();
+    for rounds in [0, 1, 20, 1000, 10_000, 100_000] {
+        assert_eq!(
+            projected_business(&input, rounds),
+            monkey_business(&input, rounds, Relief(1))
+        );
+    }
+    assert_eq!(
+        projected_business(&input, 100_000_000),
+        271_423_813_768_626_960
+    );
+}
@@ -0,0 +1,41 @@
+use utils::generate::{Rng, StdRng};
+
+/// Writes a square heightmap `size` squares wide, at least 14
+///
+/// The terrain rises evenly from `S` in the top left corner to `E` in the
+/// bottom right one, with cliffs scattered around. The top row and the
+/// right column are never cliffs, so there is always a way up.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(14);
+    let slope = 2 * (side - 1);
+    let rows: Vec<String> = (0..side)
+        .map(|row| {
+            (0..side)
+                .map(|col| {
+                    let height = (row + col) * 25 / slope;
+                    let on_path = row == 0 || col == side - 1;
+                    if (row, col) == (0, 0) {
+                        'S'
+                    } else if (row, col) == (side - 1, side - 1) {
+                        'E'
+                    } else if !on_path && height <= 20 && rng.gen_bool(0.2) {
+                        letter(rng.gen_range(height + 2..=height + 5))
+                    } else {
+                        letter(height)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n") + "\n"
+}
+
+fn letter(height: usize) -> char {
+    char::from(b'a' + u8::try_from(height).unwrap_or(25))
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,377 @@
+use anyhow::Context;
+use itertools::Itertools;
+use ndarray::Array2;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+use utils::{
+    grid::{Grid, Pos},
+    search::{Explored, Searcher, Step},
+};
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// The heightmap, with the altitude of every square from 0 to 25
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    pub start: Pos,
+    pub end: Pos,
+    pub grid: Grid<u8>,
+}
+
+/// Squares next to `pos` that can be climbed to, at most one higher
+fn walk_up(grid: &Grid<u8>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    let altitude = grid[pos];
+    grid.neighbors4(pos)
+        .filter(move |&p| grid[p] <= altitude + 1)
+}
+
+/// Squares next to `pos` that `pos` could have been climbed to from
+fn walk_down(grid: &Grid<u8>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    let altitude = grid[pos];
+    grid.neighbors4(pos)
+        .filter(move |&p| altitude <= grid[p] + 1)
+}
+
+/// Reads the heightmap, where `S` is at the lowest and `E` at the highest
+/// altitude
+///
+/// # Errors
+///
+/// Fails if a square isn't a lowercase letter, `S` or `E`, if the lines
+/// have different lengths, or if `S` or `E` is missing
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{combinator::map_res, error::context};
+    use utils::parsers::char_grid;
+
+    let square = |c: char| (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c);
+    let heightmap = map_res(char_grid(square), |squares: Grid<char>| {
+        let start = squares.position(|&c| c == 'S').ok_or("no S")?;
+        let end = squares.position(|&c| c == 'E').ok_or("no E")?;
+        let grid = squares.map(|&c| match c {
+            'S' => 0,
+            'E' => 25,
+            c => c as u8 - b'a',
+        });
+        Ok::<_, &str>(InputData { start, end, grid })
+    });
+    context("heightmap", heightmap)(input)
+}
+
+/// Searches up from the start in part 1, or down from the top to the
+/// lowest ground in part 2
+fn search(
+    input: &InputData,
+    part: u8,
+    searcher: &mut Searcher<Pos, usize>,
+) -> Explored<Pos, usize> {
+    if part == 1 {
+        searcher.bfs(
+            [input.start],
+            |&p| walk_up(&input.grid, p),
+            |p| p == &input.end,
+        )
+    } else {
+        searcher.bfs(
+            [input.end],
+            |&p| walk_down(&input.grid, p),
+            |&p| input.grid[p] == 0,
+        )
+    }
+}
+
+/// Shortest route of a part, with how far the search got everywhere else
+#[derive(Debug, PartialEq, Eq)]
+pub struct Route {
+    /// Squares from the start to the goal of the part, both included
+    pub path: Vec<Pos>,
+    /// Steps to every square the search reached, from `S` in part 1 and
+    /// from `E` in part 2
+    pub distances: Array2<Option<usize>>,
+}
+
+impl Route {
+    /// Searches the route of the part, from its start to its goal
+    ///
+    /// # Errors
+    ///
+    /// Fails if the goal can't be reached from the start
+    pub fn find(input: &InputData, part: u8) -> AocResult<Self> {
+        let explored = search(input, part, &mut Searcher::new());
+        let path = explored.path().with_context(|| {
+            if part == 1 {
+                "no path from S to E"
+            } else {
+                "no path from E down to the lowest altitude"
+            }
+        })?;
+        let distances = Array2::from_shape_fn(input.grid.dim(), |pos| explored.distance(&pos));
+        Ok(Self { path, distances })
+    }
+
+    /// Steps taken along the route
+    #[must_use]
+    pub fn steps(&self) -> usize {
+        self.path.len() - 1
+    }
+}
+
+impl AocAnswer for Route {
+    fn answer(&self) -> Answer {
+        self.steps().answer()
+    }
+
+    /// The distance of every square, with `.` where the search never got
+    fn details(&self) -> Option<String> {
+        let width = self
+            .distances
+            .iter()
+            .flatten()
+            .max()
+            .map_or(1, |d| d.to_string().len());
+        let cell = |d: &Option<usize>| match d {
+            Some(d) => format!("{d:>width$}"),
+            None => format!("{:>width$}", "."),
+        };
+        let rows = self.distances.rows().into_iter();
+        Some(rows.map(|row| row.iter().map(cell).join(" ")).join("\n"))
+    }
+}
+
+/// Fewest steps from `S` to `E`
+///
+/// # Errors
+///
+/// Fails if there's no way up to `E`
+pub fn part1(input: &InputData) -> AocResult<Route> {
+    Route::find(input, 1)
+}
+
+/// Fewest steps from any square at the lowest altitude to `E`
+///
+/// # Errors
+///
+/// Fails if there's no way down from `E` to the lowest altitude
+pub fn part2(input: &InputData) -> AocResult<Route> {
+    Route::find(input, 2)
+}
+
+/// The heightmap shaded from green valleys to white peaks, with the route on top
+#[allow(clippy::cast_precision_loss)]
+fn svg(input: &InputData, part: u8, _params: &Params) -> AocResult<svg::Svg> {
+    use utils::{
+        geom::Point2,
+        svg::{Shape, Style, Svg},
+    };
+
+    let shade = |low: u8, high: u8, altitude: u8| {
+        let (low, high) = (f64::from(low), f64::from(high));
+        (low + (high - low) * f64::from(altitude) / 25.0).round()
+    };
+    let (rows, cols) = input.grid.dim();
+    let mut svg = Svg::new(Point2::new(0.0, 0.0), cols as f64, rows as f64)
+        .with_title(format!("Day 12 part {part}"));
+    svg.grid(&input.grid, |&altitude| {
+        Some(Style::fill(format!(
+            "rgb({},{},{})",
+            shade(0x1b, 0xf5, altitude),
+            shade(0x5e, 0xf5, altitude),
+            shade(0x20, 0xdc, altitude)
+        )))
+    });
+    let cell = |&(row, col): &Pos| Point2::new(col, row);
+    let route = Route::find(input, part)?.path;
+    svg.add(
+        &Shape::path(route.iter().map(cell)),
+        &Style::stroke("red", 0.25),
+    );
+    for (pos, label) in [(input.start, "S"), (input.end, "E")] {
+        let center = svg::center(cell(&pos));
+        svg.add(
+            &Shape::Text(Point2::new(center.x, center.y + 0.35), label.to_string()),
+            &Style::fill("black"),
+        );
+    }
+    Ok(svg)
+}
+
+/// The search spreading over the heightmap one step of distance at a
+/// time, then the route it found
+struct Wave {
+    start: Pos,
+    end: Pos,
+    grid: Grid<u8>,
+    /// Positions expanded at each distance from the start
+    layers: Vec<Vec<Pos>>,
+    route: Vec<Pos>,
+    visited: usize,
+    expanded: usize,
+    /// Layers shown so far, one more than there are once the route is shown
+    shown: usize,
+}
+
+impl Wave {
+    fn new(input: &InputData, part: u8) -> Self {
+        let mut layers: Vec<Vec<Pos>> = Vec::new();
+        let explored = {
+            let mut searcher = Searcher::new().with_callback(|step: Step<Pos, usize>| {
+                if let Step::Expand { node, cost } = step {
+                    layers.resize_with(layers.len().max(cost + 1), Vec::new);
+                    layers[cost].push(*node);
+                }
+            });
+            search(input, part, &mut searcher)
+        };
+        Self {
+            start: input.start,
+            end: input.end,
+            grid: input.grid.clone(),
+            layers,
+            route: explored.path().unwrap_or_default(),
+            visited: explored.visited,
+            expanded: explored.expanded,
+            shown: 1,
+        }
+    }
+}
+
+impl viz::Visualize for Wave {
+    #[allow(clippy::cast_possible_truncation)]
+    fn frame(&self) -> viz::Frame {
+        let (rows, cols) = self.grid.dim();
+        let mut frame = viz::Frame::blank(cols, rows);
+        for ((row, col), &altitude) in self.grid.indexed_iter() {
+            frame.paint(col, row, char::from(b'a' + altitude), viz::Color::Grey);
+        }
+        for ((row, col), c) in [(self.start, 'S'), (self.end, 'E')] {
+            frame.paint(col, row, c, viz::Color::Grey);
+        }
+        let layers = &self.layers[..self.shown.min(self.layers.len())];
+        let farthest = self.layers.len().max(2) - 1;
+        for (distance, layer) in layers.iter().enumerate() {
+            // From blue near the start to yellow far away
+            let blend = |near: usize, far: usize| {
+                ((near * (farthest - distance) + far * distance) / farthest) as u8
+            };
+            let color = viz::Color::Rgb(blend(64, 255), blend(112, 255), blend(224, 64));
+            for &(row, col) in layer {
+                let (c, _) = frame.get(col, row).unwrap_or((' ', None));
+                frame.paint(col, row, c, color);
+            }
+        }
+        frame.caption = if self.shown > self.layers.len() {
+            for &(row, col) in &self.route {
+                let (c, _) = frame.get(col, row).unwrap_or((' ', None));
+                frame.paint(col, row, c.to_ascii_uppercase(), viz::Color::Red);
+            }
+            format!(
+                "route of {} steps, visited {} and expanded {} squares",
+                self.route.len().saturating_sub(1),
+                self.visited,
+                self.expanded
+            )
+        } else {
+            let expanded: usize = layers.iter().map(Vec::len).sum();
+            format!(
+                "distance {}, expanded {expanded} of {} squares",
+                self.shown - 1,
+                self.expanded
+            )
+        };
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        if self.shown > self.layers.len() {
+            return false;
+        }
+        self.shown += 1;
+        true
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn visualize(input: &InputData, part: u8, _params: &Params) -> AocResult<Box<dyn viz::Visualize>> {
+    Ok(Box::new(Wave::new(input, part)))
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(12, parse, part1, part2)
+        .with_visualize(visualize)
+        .with_svg(svg)
+}
+
+aoc_examples!();
+
+#[test]
+#[allow(clippy::zero_prefixed_literal)]
+fn test() {
+    use ndarray::array;
+
+    let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+    assert_parser!(
+        parse,
+        input,
+        InputData {
+            start: (0, 0),
+            end: (2, 5),
+            grid: Grid::from(array![
+                [00, 00, 01, 16, 15, 14, 13, 12],
+                [00, 01, 02, 17, 24, 23, 23, 11],
+                [00, 02, 02, 18, 25, 25, 23, 10],
+                [00, 02, 02, 19, 20, 21, 22, 09],
+                [00, 01, 03, 04, 05, 06, 07, 08]
+            ])
+        }
+    );
+}
+
+#[test]
+fn test_route() {
+    let (_, input) = parse("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+    let route = part2(&input).unwrap();
+    assert_eq!(route.answer(), Answer::Int(29));
+    assert_eq!(
+        route.details().unwrap().lines().next(),
+        Some(" . 30 29 12 13 14 15 16")
+    );
+    assert!(parse("Sab\naXE").is_err());
+    assert!(parse("Sab\nabc").is_err());
+    let (_, input) = parse("SzE").unwrap();
+    assert_eq!(
+        part1(&input).unwrap_err().to_string(),
+        "no path from S to E"
+    );
+    assert!(part2(&input).is_err());
+}
+
+#[test]
+fn test_svg() {
+    let (_, input) = parse("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+    let picture = svg(&input, 1, &Params::default()).unwrap().to_string();
+    assert_eq!(picture.matches("<rect").count(), 40);
+    assert!(picture.contains(r#"<polyline points="0.5,0.5 1.5,0.5 "#));
+    assert_eq!(picture.matches("<text").count(), 2);
+    let picture = svg(&input, 2, &Params::default()).unwrap().to_string();
+    assert!(picture.contains(r#"<polyline points="5.5,2.5 "#));
+}
+
+#[test]
+fn test_visualize() {
+    let (_, input) = parse("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+    let frames = viz::frames(
+        visualize(&input, 1, &Params::default()).unwrap().as_mut(),
+        100,
+    );
+    assert_eq!(frames.len(), 32);
+    assert_eq!(frames[0].caption, "distance 0, expanded 1 of 39 squares");
+    assert_eq!(
+        frames[31].caption,
+        "route of 31 steps, visited 40 and expanded 39 squares"
+    );
+    let lines = ["SABQPONM", "abCRYXXL", "acCSZEXK", "acCTUVWJ", "abDEFGHI"];
+    assert_eq!(frames[31].to_string(), lines.join("\n"));
+}
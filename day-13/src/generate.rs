@@ -0,0 +1,54 @@
+use crate::{divider_packets, Packet};
+use utils::generate::{Rng, StdRng};
+
+const MAX_DEPTH: usize = 4;
+
+/// Writes `size` pairs of packets
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| format!("{}\n{}", packet(rng), packet(rng)))
+        .collect();
+    pairs.join("\n\n") + "\n"
+}
+
+/// A packet that doesn't compare equal to a divider, so the dividers
+/// have a place of their own once sorted
+fn packet(rng: &mut StdRng) -> String {
+    loop {
+        let packet = list(rng, 0);
+        let (_, parsed) = Packet::parse(&packet).expect("generated packets parse");
+        if !divider_packets().contains(&parsed) {
+            return packet;
+        }
+    }
+}
+
+fn list(rng: &mut StdRng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth < MAX_DEPTH && rng.gen_bool(0.3) {
+                list(rng, depth + 1)
+            } else {
+                rng.gen_range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+    for seed in 0..8 {
+        let input = generate(&mut utils::generate::rng(seed), 200);
+        let (_, parsed) = crate::parse(&input).unwrap();
+        let packets = parsed.packet_pairs.iter().flat_map(|(l, r)| [l, r]);
+        assert!(packets.clone().all(|p| !divider_packets().contains(p)));
+        // The decoder key is the product of two different places
+        let below = |divider: &Packet| packets.clone().filter(|&p| p < divider).count();
+        let [two, six] = divider_packets();
+        let key = (below(&two) + 1) * (below(&six) + 2);
+        assert_eq!(crate::part2(&parsed).unwrap(), key);
+    }
+}
@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// A packet, ordered the way the distress signal wants them sorted
+#[derive(Debug, Clone, Eq)]
+pub enum Packet {
+    List(Vec<Self>),
+    Value(i32),
+}
+
+impl Packet {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        use nom::{
+            branch::alt,
+            character::complete::{char, i32},
+            combinator::map,
+            multi::separated_list0,
+            sequence::delimited,
+        };
+
+        let list = map(
+            delimited(
+                char('['),
+                separated_list0(char(','), Self::parse),
+                char(']'),
+            ),
+            Self::List,
+        );
+        let value = map(i32, Self::Value);
+        alt((value, list))(input)
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Value(l), Self::Value(r)) => l.cmp(r),
+            (Self::List(l), Self::List(r)) => l.cmp(r),
+            (Self::Value(l), Self::List(_)) => Self::List(vec![Self::Value(*l)]).cmp(other),
+            (Self::List(_), Self::Value(r)) => self.cmp(&Self::List(vec![Self::Value(*r)])),
+        }
+    }
+}
+
+/// The pairs of packets, in the order they were received
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    pub packet_pairs: Vec<(Packet, Packet)>,
+}
+
+/// Parses pairs of packets on two lines, separated by blank lines
+///
+/// # Errors
+///
+/// Fails if a packet isn't a bracketed list of numbers and lists
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{character::complete::line_ending, combinator::map, sequence::separated_pair};
+    use utils::parsers::blocks;
+    let packet_pair = separated_pair(Packet::parse, line_ending, Packet::parse);
+    let mut parser = map(blocks(packet_pair), |packet_pairs| InputData {
+        packet_pairs,
+    });
+    parser(input)
+}
+
+/// Sum of the indices of the pairs already in the right order
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData) -> AocResult<usize> {
+    Ok(input
+        .packet_pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (l, r))| if l < r { Some(i + 1) } else { None })
+        .sum())
+}
+
+/// The `[[2]]` and `[[6]]` packets added to the received ones in part 2
+fn divider_packets() -> [Packet; 2] {
+    [
+        Packet::List(vec![Packet::List(vec![Packet::Value(2)])]),
+        Packet::List(vec![Packet::List(vec![Packet::Value(6)])]),
+    ]
+}
+
+/// Decoder key, from where the divider packets end up once every packet is sorted
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData) -> AocResult<usize> {
+    let divider_packets = divider_packets();
+    Ok(input
+        .packet_pairs
+        .iter()
+        .flat_map(|(l, r)| [l, r])
+        .chain(divider_packets.iter())
+        .sorted()
+        .enumerate()
+        .filter_map(|(i, p)| {
+            if divider_packets.contains(p) {
+                Some(i + 1)
+            } else {
+                None
+            }
+        })
+        .product())
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(13, parse, part1, part2)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    use Packet::{List, Value};
+
+    let input = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+    assert_parser!(
+        parse,
+        input,
+        InputData {
+            packet_pairs: vec![
+                (
+                    List(vec![Value(1), Value(1), Value(3), Value(1), Value(1)]),
+                    List(vec![Value(1), Value(1), Value(5), Value(1), Value(1)])
+                ),
+                (
+                    List(vec![
+                        List(vec![Value(1)]),
+                        List(vec![Value(2), Value(3), Value(4)])
+                    ]),
+                    List(vec![List(vec![Value(1)]), Value(4)]),
+                ),
+                (
+                    List(vec![Value(9)]),
+                    List(vec![List(vec![Value(8), Value(7), Value(6)])])
+                ),
+                (
+                    List(vec![List(vec![Value(4), Value(4)]), Value(4), Value(4)]),
+                    List(vec![
+                        List(vec![Value(4), Value(4)]),
+                        Value(4),
+                        Value(4),
+                        Value(4),
+                    ])
+                ),
+                (
+                    List(vec![Value(7), Value(7), Value(7), Value(7)]),
+                    List(vec![Value(7), Value(7), Value(7)])
+                ),
+                (List(vec![]), List(vec![Value(3)])),
+                (
+                    List(vec![List(vec![List(vec![])])]),
+                    List(vec![List(vec![])])
+                ),
+                (
+                    List(vec![
+                        Value(1),
+                        List(vec![
+                            Value(2),
+                            List(vec![
+                                Value(3),
+                                List(vec![Value(4), List(vec![Value(5), Value(6), Value(7)])])
+                            ])
+                        ]),
+                        Value(8),
+                        Value(9)
+                    ]),
+                    List(vec![
+                        Value(1),
+                        List(vec![
+                            Value(2),
+                            List(vec![
+                                Value(3),
+                                List(vec![Value(4), List(vec![Value(5), Value(6), Value(0)])])
+                            ])
+                        ]),
+                        Value(8),
+                        Value(9)
+                    ])
+                )
+            ]
+        }
+    );
+}
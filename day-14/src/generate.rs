@@ -0,0 +1,35 @@
+use utils::generate::{Rng, StdRng};
+
+const SOURCE: usize = 500;
+
+/// Writes `size` paths of rock below the sand source
+///
+/// The cave gets deeper with more paths, up to 400 below the source.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let depth = (size * 2).clamp(10, 400);
+    let (left, right) = (SOURCE - depth / 2, SOURCE + depth / 2);
+    let paths: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let mut x = rng.gen_range(left..=right);
+            let mut y = rng.gen_range(5..=depth);
+            let mut points = vec![format!("{x},{y}")];
+            let horizontal = rng.gen_bool(0.5);
+            for segment in 0..rng.gen_range(1..=5) {
+                if (segment % 2 == 0) == horizontal {
+                    x = rng.gen_range(x.saturating_sub(8).max(left)..=(x + 8).min(right));
+                } else {
+                    y = rng.gen_range(y.saturating_sub(8).max(5)..=(y + 8).min(depth));
+                }
+                points.push(format!("{x},{y}"));
+            }
+            points.join(" -> ")
+        })
+        .collect();
+    paths.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
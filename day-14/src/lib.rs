@@ -0,0 +1,242 @@
+use itertools::Itertools;
+use ndarray::s;
+use utils::grid::{Grid, Pos};
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// The rock walls, each as the corners of its path
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    pub walls: Vec<Vec<(usize, usize)>>,
+}
+
+/// Parses one wall per line, like `498,4 -> 498,6 -> 496,6`
+///
+/// # Errors
+///
+/// Fails if a line isn't points separated by arrows
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{char, line_ending, u32},
+        combinator::map,
+        multi::separated_list1,
+        sequence::separated_pair,
+    };
+
+    let pair = map(separated_pair(u32, char(','), u32), |(x, y)| {
+        (x as usize, y as usize)
+    });
+    let wall = separated_list1(tag(" -> "), pair);
+    let walls = separated_list1(line_ending, wall);
+    let mut parser = map(walls, |walls| InputData { walls });
+    parser(input)
+}
+
+const fn min_max(l: usize, r: usize) -> (usize, usize) {
+    if l < r {
+        (l, r)
+    } else {
+        (r, l)
+    }
+}
+
+/// Where sand falling from `start` comes to rest, if it doesn't fall out
+/// of the cave
+fn settle_sand(start: Pos, cave: &Grid<bool>) -> Option<Pos> {
+    let (mut y, x) = start;
+    while !*cave.get((y, x))? {
+        y += 1;
+    }
+    let side = |delta| {
+        cave.offset((y, x), (0, delta))
+            .map(|side| (side, cave[side]))
+    };
+    match (side(-1), side(1)) {
+        (Some((left, false)), _) => settle_sand(left, cave),
+        (_, Some((right, false))) => settle_sand(right, cave),
+        (Some(_), Some(_)) => Some((y - 1, x)),
+        _ => None,
+    }
+}
+
+/// Where the sand comes from, as `(y, x)`
+const SOURCE: Pos = (0, 500);
+
+/// Marks the rock of the walls, and the floor under them if there is one,
+/// with the cave's rows going down from the source
+fn build_cave(input: &InputData, floor: bool) -> Grid<bool> {
+    let (max_x, max_y) = input
+        .walls
+        .iter()
+        .flatten()
+        .fold((usize::MIN, usize::MIN), |(max_x, max_y), (x, y)| {
+            (max_x.max(*x), max_y.max(*y))
+        });
+    let mut cave = if floor {
+        let mut cave = Grid::filled(max_y + 3, max_x * 2, false);
+        cave.row_mut(max_y + 2).fill(true);
+        cave
+    } else {
+        Grid::filled(max_y + 1, max_x + 1, false)
+    };
+    for wall in &input.walls {
+        for ((x1, y1), (x2, y2)) in wall.iter().tuple_windows() {
+            let (x_min, x_max) = min_max(*x1, *x2);
+            let (y_min, y_max) = min_max(*y1, *y2);
+            cave.slice_mut(s![y_min..=y_max, x_min..=x_max]).fill(true);
+        }
+    }
+    cave
+}
+
+/// Units of sand that come to rest before sand flows into the abyss
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData) -> AocResult<usize> {
+    let mut cave = build_cave(input, false);
+    let mut steps = 0;
+    while let Some(pos) = settle_sand(SOURCE, &cave) {
+        steps += 1;
+        cave[pos] = true;
+    }
+
+    Ok(steps)
+}
+
+/// Units of sand that come to rest once there's a floor, until the source
+/// is blocked
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData) -> AocResult<usize> {
+    let mut cave = build_cave(input, true);
+    let mut steps: usize = 1;
+    loop {
+        if let Some(pos) = settle_sand(SOURCE, &cave) {
+            if pos == SOURCE {
+                break;
+            }
+            steps += 1;
+            cave[pos] = true;
+        }
+    }
+    Ok(steps)
+}
+
+/// Sand falling into the cave one grain at a time
+struct Sand {
+    rock: Grid<bool>,
+    filled: Grid<bool>,
+    grains: usize,
+    /// Columns of the cave in the frame
+    columns: std::ops::RangeInclusive<usize>,
+    done: bool,
+}
+
+impl Sand {
+    fn new(input: &InputData, part: u8) -> Self {
+        let rock = build_cave(input, part == 2);
+        let depth = rock.height();
+        let columns = if part == 2 {
+            SOURCE.1 - depth..=SOURCE.1 + depth
+        } else {
+            let xs = input.walls.iter().flatten().map(|(x, _)| *x);
+            xs.clone().min().unwrap_or(SOURCE.1) - 1..=xs.max().unwrap_or(SOURCE.1) + 1
+        };
+        Self {
+            filled: rock.clone(),
+            rock,
+            grains: 0,
+            columns,
+            done: false,
+        }
+    }
+}
+
+impl viz::Visualize for Sand {
+    fn frame(&self) -> viz::Frame {
+        let width = self.columns.clone().count();
+        let mut frame = viz::Frame::new(width, self.rock.height())
+            .with_caption(format!("{} grains of sand", self.grains));
+        for (x, column) in self.columns.clone().enumerate() {
+            for y in 0..frame.height() {
+                match (self.rock.get((y, column)), self.filled.get((y, column))) {
+                    (Some(true), _) => frame.paint(x, y, '#', viz::Color::Grey),
+                    (_, Some(true)) => frame.paint(x, y, 'o', viz::Color::Yellow),
+                    _ => {}
+                }
+            }
+        }
+        if !self.filled[SOURCE] {
+            frame.set(SOURCE.1 - self.columns.start(), SOURCE.0, '+');
+        }
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let Some(pos) = settle_sand(SOURCE, &self.filled) else {
+            self.done = true;
+            return false;
+        };
+        self.filled[pos] = true;
+        self.grains += 1;
+        self.done = pos == SOURCE;
+        true
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn visualize(input: &InputData, part: u8, _params: &Params) -> AocResult<Box<dyn viz::Visualize>> {
+    Ok(Box::new(Sand::new(input, part)))
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(14, parse, part1, part2).with_visualize(visualize)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    let input = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+    assert_parser!(
+        parse,
+        input,
+        InputData {
+            walls: vec![
+                vec![(498, 4), (498, 6), (496, 6)],
+                vec![(503, 4), (502, 4), (502, 9), (494, 9)],
+            ]
+        }
+    );
+}
+
+#[test]
+fn test_visualize() {
+    let input = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+    let (_, input) = parse(input).unwrap();
+    let frames = viz::frames(&mut Sand::new(&input, 1), usize::MAX);
+    assert_eq!(frames.len(), 25);
+    assert_eq!(
+        frames[24].to_string(),
+        "\
+.......+....
+............
+.......o....
+......ooo...
+.....#ooo##.
+....o#ooo#..
+...###ooo#..
+.....oooo#..
+..o.ooooo#..
+.#########.."
+    );
+    assert_eq!(viz::frames(&mut Sand::new(&input, 2), usize::MAX).len(), 94);
+}
@@ -0,0 +1,75 @@
+use utils::generate::{Rng, SliceRandom, StdRng};
+
+const AREA: i64 = 4_000_000;
+
+/// Writes `size` sensors and the beacons closest to them, at least the
+/// four that leave a single position of the area uncovered
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    generate_in(rng, size, AREA)
+}
+
+/// Sensors around a hidden position up to `area` on both axes
+///
+/// Four sensors just outside the corners of the area each cover the
+/// quarter of it on their side of the hidden position, but not the
+/// position itself. The others are anywhere in the area and stop short of
+/// the hidden position.
+fn generate_in(rng: &mut StdRng, size: usize, area: i64) -> String {
+    let (hx, hy) = (rng.gen_range(0..=area), rng.gen_range(0..=area));
+    let k = area + 1;
+    let mut pairs: Vec<((i64, i64), (i64, i64))> = (0..size.saturating_sub(4))
+        .map(|_| loop {
+            let (sx, sy) = (rng.gen_range(0..=area), rng.gen_range(0..=area));
+            let hidden = (sx - hx).abs() + (sy - hy).abs();
+            if hidden <= 1 {
+                continue;
+            }
+            let reach = rng.gen_range(1..=(hidden - 1).min((area / 4).max(1)));
+            let dx = rng.gen_range(-reach..=reach);
+            let dy = if rng.gen_bool(0.5) {
+                reach - dx.abs()
+            } else {
+                dx.abs() - reach
+            };
+            break ((sx, sy), (sx + dx, sy + dy));
+        })
+        .collect();
+    pairs.extend([
+        ((hx - k, hy - k), (hx - 1, hy)),
+        ((hx + k, hy - k), (hx + 1, hy)),
+        ((hx - k, hy + k), (hx - 1, hy)),
+        ((hx + k, hy + k), (hx + 1, hy)),
+    ]);
+    pairs.shuffle(rng);
+    let sensors: Vec<String> = pairs
+        .into_iter()
+        .map(|((sx, sy), (bx, by))| {
+            format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}")
+        })
+        .collect();
+    sensors.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+    for seed in 0..8 {
+        for size in [1, 4, 30] {
+            let input = generate_in(&mut utils::generate::rng(seed), size, 20);
+            let (_, parsed) = crate::parse(&input).unwrap();
+            let uncovered: Vec<(i64, i64)> = (0..=20)
+                .flat_map(|y| (0..=20).map(move |x| (x, y)))
+                .filter(|&(x, y)| {
+                    parsed.pairs.iter().all(|pair| {
+                        let (sx, sy) = pair.sensor;
+                        let (bx, by) = pair.beacon;
+                        (sx - x).abs() + (sy - y).abs() > (sx - bx).abs() + (sy - by).abs()
+                    })
+                })
+                .collect();
+            assert_eq!(uncovered.len(), 1, "seed {seed}, size {size}");
+            assert_eq!(crate::distress_beacon(&parsed, 20), Some(uncovered[0]));
+        }
+    }
+}
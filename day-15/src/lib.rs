@@ -0,0 +1,292 @@
+#![allow(clippy::cast_possible_wrap)]
+use itertools::Itertools;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+use utils::{
+    geom::Point2,
+    interval::{Interval, IntervalSet},
+};
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// A sensor and the closest beacon to it
+#[derive(Debug, PartialEq)]
+pub struct SensorBeaconPair {
+    pub sensor: (i64, i64),
+    pub beacon: (i64, i64),
+}
+
+impl SensorBeaconPair {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        use nom::{
+            bytes::complete::tag,
+            combinator::map,
+            error::context,
+            sequence::{preceded, separated_pair},
+        };
+        use utils::parsers::xy_pair;
+        let sensor = context("sensor", preceded(tag("Sensor at "), xy_pair));
+        let beacon = context("beacon", preceded(tag("closest beacon is at "), xy_pair));
+        map(
+            context("sensor line", separated_pair(sensor, tag(": "), beacon)),
+            |(sensor, beacon)| Self { sensor, beacon },
+        )(input)
+    }
+
+    fn range_in_row(&self, row: i64) -> Option<Interval<i64>> {
+        let &Self {
+            sensor: (sx, sy),
+            beacon: _,
+        } = self;
+        let distance = Point2::from(self.sensor).manhattan(self.beacon.into());
+        let reach = distance - sy.abs_diff(row) as i64;
+        (reach >= 0).then(|| Interval::new(sx - reach, sx + reach))
+    }
+}
+
+/// Every sensor and the beacon it found
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    pub pairs: Vec<SensorBeaconPair>,
+}
+
+impl InputData {
+    fn coverage(&self, row: i64) -> IntervalSet<i64> {
+        self.pairs
+            .iter()
+            .filter_map(|p| p.range_in_row(row))
+            .collect()
+    }
+}
+
+/// Parses one sensor per line, like
+/// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`
+///
+/// # Errors
+///
+/// Fails if a line doesn't describe a sensor and its beacon
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::combinator::map;
+    use utils::parsers::lines;
+    map(lines(SensorBeaconPair::parse), |pairs| InputData { pairs })(input)
+}
+
+pub const PARAMS: &[Param] = &[
+    Param::new("row", "2000000", "row to count the covered positions of"),
+    Param::new(
+        "max",
+        "4000000",
+        "largest coordinate the distress beacon can be at",
+    ),
+];
+
+fn calc_part1(input: &InputData, row: i64) -> i64 {
+    let coverage = input.coverage(row);
+    let beacons = input
+        .pairs
+        .iter()
+        .map(|&SensorBeaconPair { sensor: _, beacon }| beacon)
+        .filter(|&(x, y)| y == row && coverage.contains(x))
+        .unique()
+        .count();
+    coverage.total_len() - beacons as i64
+}
+
+/// Positions in the `row` parameter's row where the distress beacon can't be
+///
+/// # Errors
+///
+/// Fails if the `row` parameter isn't a number
+pub fn part1(input: &InputData, params: &Params) -> AocResult<i64> {
+    Ok(calc_part1(input, params.get("row")?))
+}
+
+/// The one position up to `max` on both axes no sensor covers
+fn distress_beacon(input: &InputData, max: i64) -> Option<(i64, i64)> {
+    let search_area = IntervalSet::from_iter([0..=max]);
+    (0..=max).find_map(|row| {
+        let missing = search_area.difference(&input.coverage(row));
+        missing.iter().next().map(|i| (i.start, row))
+    })
+}
+
+fn calc_part2(input: &InputData, max: i64) -> i64 {
+    const TUNING_FREQUENCY: i64 = 4_000_000;
+
+    distress_beacon(input, max).map_or(0, |(x, y)| x * TUNING_FREQUENCY + y)
+}
+
+/// Tuning frequency of the distress beacon, which is no further than `max`
+/// on both axes
+///
+/// # Errors
+///
+/// Fails if the `max` parameter isn't a number
+pub fn part2(input: &InputData, params: &Params) -> AocResult<i64> {
+    Ok(calc_part2(input, params.get("max")?))
+}
+
+/// The reach of every sensor, with the row of part 1 or the distress
+/// beacon of part 2
+#[allow(clippy::cast_precision_loss)]
+fn svg(input: &InputData, part: u8, params: &Params) -> AocResult<svg::Svg> {
+    use utils::svg::{Shape, Style, Svg};
+
+    let point = |(x, y): (i64, i64)| Point2::new(x as f64, y as f64);
+    let reaches: Vec<(Point2<f64>, f64)> = input
+        .pairs
+        .iter()
+        .map(|p| {
+            let distance = Point2::from(p.sensor).manhattan(p.beacon.into());
+            (point(p.sensor), distance as f64)
+        })
+        .collect();
+    let (mut min, mut max) = (point(input.pairs[0].sensor), point(input.pairs[0].sensor));
+    for (sensor, reach) in &reaches {
+        min = Point2::new(min.x.min(sensor.x - reach), min.y.min(sensor.y - reach));
+        max = Point2::new(max.x.max(sensor.x + reach), max.y.max(sensor.y + reach));
+    }
+    let (width, height) = (max.x - min.x, max.y - min.y);
+    // Sizes of marks, so they look the same whatever the size of the input
+    let unit = width.max(height) / 200.0;
+    let mut svg = Svg::new(min, width, height)
+        .with_width(800.0)
+        .with_background("#0f0f23")
+        .with_title(format!("Day 15 part {part}"));
+    for &(sensor, reach) in &reaches {
+        svg.add(
+            &Shape::Diamond(sensor, reach),
+            &Style::fill("#4070e0")
+                .with_opacity(0.3)
+                .with_stroke("#4070e0", unit / 4.0),
+        );
+    }
+    for pair in &input.pairs {
+        svg.add(
+            &Shape::Circle(point(pair.sensor), unit),
+            &Style::fill("#ffff66"),
+        );
+        svg.add(
+            &Shape::Circle(point(pair.beacon), unit),
+            &Style::fill("#e04040"),
+        );
+    }
+    if part == 1 {
+        let row = params.get::<i64>("row")? as f64;
+        svg.add(
+            &Shape::Polyline(vec![Point2::new(min.x, row), Point2::new(max.x, row)]),
+            &Style::stroke("#00cc00", unit / 2.0),
+        );
+    } else if let Some(beacon) = distress_beacon(input, params.get("max")?) {
+        svg.add(
+            &Shape::Circle(point(beacon), unit * 2.0),
+            &Style::stroke("#00cc00", unit / 2.0),
+        );
+    }
+    Ok(svg)
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::with_params(15, parse, part1, part2, PARAMS).with_svg(svg)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    let test_data = InputData {
+        pairs: vec![
+            SensorBeaconPair {
+                sensor: (2, 18),
+                beacon: (-2, 15),
+            },
+            SensorBeaconPair {
+                sensor: (9, 16),
+                beacon: (10, 16),
+            },
+            SensorBeaconPair {
+                sensor: (13, 2),
+                beacon: (15, 3),
+            },
+            SensorBeaconPair {
+                sensor: (12, 14),
+                beacon: (10, 16),
+            },
+            SensorBeaconPair {
+                sensor: (10, 20),
+                beacon: (10, 16),
+            },
+            SensorBeaconPair {
+                sensor: (14, 17),
+                beacon: (10, 16),
+            },
+            SensorBeaconPair {
+                sensor: (8, 7),
+                beacon: (2, 10),
+            },
+            SensorBeaconPair {
+                sensor: (2, 0),
+                beacon: (2, 10),
+            },
+            SensorBeaconPair {
+                sensor: (0, 11),
+                beacon: (2, 10),
+            },
+            SensorBeaconPair {
+                sensor: (20, 14),
+                beacon: (25, 17),
+            },
+            SensorBeaconPair {
+                sensor: (17, 20),
+                beacon: (21, 22),
+            },
+            SensorBeaconPair {
+                sensor: (16, 7),
+                beacon: (15, 3),
+            },
+            SensorBeaconPair {
+                sensor: (14, 3),
+                beacon: (15, 3),
+            },
+            SensorBeaconPair {
+                sensor: (20, 1),
+                beacon: (15, 3),
+            },
+        ],
+    };
+    assert_parser!(parse, input, test_data);
+}
+
+#[test]
+fn test_svg() {
+    let input = include_str!("../examples/1.txt");
+    let (_, input) = parse(input).unwrap();
+    let params = Params::new(PARAMS, &[("row", "10"), ("max", "20")]).unwrap();
+    assert_eq!(distress_beacon(&input, 20), Some((14, 11)));
+    let picture = svg(&input, 1, &params).unwrap().to_string();
+    assert_eq!(picture.matches("<polygon").count(), 14);
+    assert_eq!(picture.matches("<circle").count(), 28);
+    assert!(picture.contains(r#"<polygon points="8,-2 17,7 8,16 -1,7""#));
+    assert!(picture.contains("<polyline points=\"-8,10 "));
+    let picture = svg(&input, 2, &params).unwrap().to_string();
+    assert!(picture.contains(r#"<circle cx="14" cy="11""#));
+}
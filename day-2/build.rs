@@ -0,0 +1,3 @@
+fn main() {
+    utils::examples::generate_tests();
+}
@@ -0,0 +1,19 @@
+use utils::generate::{Rng, StdRng};
+
+/// Writes `size` rounds of the strategy guide
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let rounds: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let opponent = rng.gen_range('A'..='C');
+            let response = rng.gen_range('X'..='Z');
+            format!("{opponent} {response}")
+        })
+        .collect();
+    rounds.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,290 @@
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// Points a shape or an outcome is worth
+pub trait Score {
+    fn score(&self) -> i32;
+}
+
+/// A letter that isn't a shape or an outcome
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("Unable to parse `{0}` into Move")]
+    Move(char),
+    #[error("Unable to parse `{0}` into Outcome")]
+    Outcome(char),
+}
+
+/// A shape a player picks
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    /// The shape to play against this one to get `outcome`
+    #[must_use]
+    pub const fn rig(self, outcome: Outcome) -> Self {
+        use Move::{Paper, Rock, Scissors};
+        use Outcome::{Draw, Lose, Win};
+        match (outcome, self) {
+            (Draw, _) => self,
+            (Lose, Rock) | (Win, Paper) => Scissors,
+            (Lose, Paper) | (Win, Scissors) => Rock,
+            (Lose, Scissors) | (Win, Rock) => Paper,
+        }
+    }
+
+    /// How playing this shape against `other` ends
+    #[must_use]
+    pub const fn outcome(self, other: Self) -> Outcome {
+        use Move::{Paper, Rock, Scissors};
+        use Outcome::{Draw, Lose, Win};
+        match (self, other) {
+            (Rock, Paper) | (Paper, Scissors) | (Scissors, Rock) => Lose,
+            (Paper, Rock) | (Scissors, Paper) | (Rock, Scissors) => Win,
+            _ => Draw,
+        }
+    }
+}
+
+impl TryFrom<char> for Move {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Move::{Paper, Rock, Scissors};
+        match value {
+            'A' | 'X' => Ok(Rock),
+            'B' | 'Y' => Ok(Paper),
+            'C' | 'Z' => Ok(Scissors),
+            c => Err(ParseError::Move(c)),
+        }
+    }
+}
+
+impl Score for Move {
+    fn score(&self) -> i32 {
+        use Move::{Paper, Rock, Scissors};
+        match self {
+            Rock => 1,
+            Paper => 2,
+            Scissors => 3,
+        }
+    }
+}
+
+/// A round read as the shapes both players pick
+#[derive(Debug, PartialEq, Eq)]
+pub struct Round {
+    pub player1: Move,
+    pub player2: Move,
+}
+
+impl Round {
+    #[must_use]
+    pub fn score_player2(&self) -> i32 {
+        self.player2.score() + self.player2.outcome(self.player1).score()
+    }
+}
+
+/// How a round ends for the second player
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Outcome::{Draw, Lose, Win};
+        match value {
+            'X' => Ok(Lose),
+            'Y' => Ok(Draw),
+            'Z' => Ok(Win),
+            _ => Err(ParseError::Outcome(value)),
+        }
+    }
+}
+
+impl Score for Outcome {
+    fn score(&self) -> i32 {
+        use Outcome::{Draw, Lose, Win};
+        match self {
+            Lose => 0,
+            Draw => 3,
+            Win => 6,
+        }
+    }
+}
+
+/// A round read as the first player's shape and how it should end
+#[derive(Debug, PartialEq, Eq)]
+pub struct StrategicRound {
+    pub player1: Move,
+    pub outcome: Outcome,
+}
+
+impl StrategicRound {
+    #[must_use]
+    pub fn score_player2(&self) -> i32 {
+        self.player1.rig(self.outcome).score() + self.outcome.score()
+    }
+}
+
+/// The strategy guide as part 1 reads it
+#[derive(Debug, PartialEq, Eq)]
+pub struct InputData1 {
+    pub rounds: Vec<Round>,
+}
+/// The strategy guide as part 2 reads it
+#[derive(Debug, PartialEq, Eq)]
+pub struct InputData2 {
+    pub rounds: Vec<StrategicRound>,
+}
+
+/// Parses one round per line, with the second column as a shape
+///
+/// # Errors
+///
+/// Fails if a line isn't two shapes separated by a space
+pub fn parse1(input: &str) -> ParseResult<'_, InputData1> {
+    use nom::{
+        character::complete::{char, line_ending, one_of},
+        combinator::{map, map_res},
+        multi::separated_list1,
+        sequence::separated_pair,
+    };
+    let player1 = one_of("ABC");
+    let player2 = one_of("XYZ");
+    let round = separated_pair(player1, char(' '), player2);
+    let round = map_res(round, |(p1, p2)| {
+        Ok::<Round, ParseError>(Round {
+            player1: p1.try_into()?,
+            player2: p2.try_into()?,
+        })
+    });
+    let rounds = separated_list1(line_ending, round);
+    let mut parse = map(rounds, |v| InputData1 { rounds: v });
+    parse(input)
+}
+
+/// Parses one round per line, with the second column as an outcome
+///
+/// # Errors
+///
+/// Fails if a line isn't a shape and an outcome separated by a space
+pub fn parse2(input: &str) -> ParseResult<'_, InputData2> {
+    use nom::{
+        character::complete::{char, line_ending, one_of},
+        combinator::{map, map_res},
+        multi::separated_list1,
+        sequence::tuple,
+    };
+    let player1 = one_of("ABC");
+    let player2 = one_of("XYZ");
+    let round = tuple((player1, char(' '), player2));
+    let round = map_res(round, |(p1, _, p2)| {
+        Ok::<StrategicRound, ParseError>(StrategicRound {
+            player1: p1.try_into()?,
+            outcome: p2.try_into()?,
+        })
+    });
+    let rounds = separated_list1(line_ending, round);
+    let mut parse = map(rounds, |v| InputData2 { rounds: v });
+    parse(input)
+}
+
+/// Total score when the guide says which shape to play
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData1) -> AocResult<i32> {
+    Ok(input.rounds.iter().map(Round::score_player2).sum())
+}
+
+/// Total score when the guide says how every round should end
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData2) -> AocResult<i32> {
+    Ok(input.rounds.iter().map(StrategicRound::score_player2).sum())
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    SplitFnSolution::new(2, parse1, parse2, part1, part2)
+}
+
+aoc_examples!();
+
+#[test]
+fn test_rounds() {
+    use Move::{Paper, Rock};
+    assert_eq!(
+        Round {
+            player1: Rock,
+            player2: Paper
+        }
+        .score_player2(),
+        8
+    );
+}
+
+#[test]
+fn test1() {
+    use Move::{Paper, Rock, Scissors};
+    let input = "A Y\nB X\nC Z";
+    assert_parser!(
+        parse1,
+        input,
+        InputData1 {
+            rounds: vec![
+                Round {
+                    player1: Rock,
+                    player2: Paper
+                },
+                Round {
+                    player1: Paper,
+                    player2: Rock
+                },
+                Round {
+                    player1: Scissors,
+                    player2: Scissors
+                },
+            ]
+        }
+    );
+}
+
+#[test]
+fn test2() {
+    use Move::{Paper, Rock, Scissors};
+    use Outcome::{Draw, Lose, Win};
+    let input = "A Y\nB X\nC Z";
+    assert_parser!(
+        parse2,
+        input,
+        InputData2 {
+            rounds: vec![
+                StrategicRound {
+                    player1: Rock,
+                    outcome: Draw,
+                },
+                StrategicRound {
+                    player1: Paper,
+                    outcome: Lose,
+                },
+                StrategicRound {
+                    player1: Scissors,
+                    outcome: Win,
+                }
+            ]
+        }
+    );
+}
@@ -0,0 +1,44 @@
+use utils::generate::{Rng, SliceRandom, StdRng};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Writes `size` groups of three rucksacks
+///
+/// Like the puzzle's, every rucksack has exactly one item type in both
+/// compartments and every group exactly one badge all three carry.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut rucksacks = Vec::new();
+    for _ in 0..size.max(1) {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(rng);
+        let badge = items.pop().unwrap();
+        // Each elf of the group packs from their own items, so the badge
+        // is the only one they share
+        for own in items.chunks(items.len() / 3) {
+            let (&shared, rest) = own.split_first().unwrap();
+            let (left, right) = rest.split_at(rest.len() / 2);
+            let len = rng.gen_range(4..=16);
+            let mut compartments = [pack(rng, left, len), pack(rng, right, len)];
+            compartments[0][0] = shared;
+            compartments[1][0] = shared;
+            compartments[rng.gen_range(0..2)][1] = badge;
+            let mut rucksack = Vec::new();
+            for mut compartment in compartments {
+                compartment.shuffle(rng);
+                rucksack.append(&mut compartment);
+            }
+            rucksacks.push(String::from_utf8_lossy(&rucksack).into_owned());
+        }
+    }
+    rucksacks.join("\n") + "\n"
+}
+
+fn pack(rng: &mut StdRng, items: &[u8], len: usize) -> Vec<u8> {
+    (0..len).map(|_| *items.choose(rng).unwrap()).collect()
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
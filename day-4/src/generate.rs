@@ -0,0 +1,20 @@
+use utils::generate::{Rng, StdRng};
+
+/// Writes `size` pairs of section assignments
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut assignment = || {
+        let start = rng.gen_range(1..=99);
+        let end = rng.gen_range(start..=99);
+        format!("{start}-{end}")
+    };
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| format!("{},{}", assignment(), assignment()))
+        .collect();
+    pairs.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,101 @@
+use std::ops::RangeInclusive;
+
+use utils::interval::Interval;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+#[derive(Debug, PartialEq, Eq)]
+/// The sections assigned to every pair of elves
+pub struct InputData {
+    pub assignments: Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>,
+}
+
+/// Parses one pair of `a-b` ranges per line
+///
+/// # Errors
+///
+/// Fails if a line isn't two ranges separated by a comma
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{character::complete::char, combinator::map, sequence::separated_pair};
+    use utils::parsers::{lines, range};
+    let assignment_pair = separated_pair(range, char(','), range);
+    let mut parser = map(lines(assignment_pair), |assignments| InputData {
+        assignments,
+    });
+    parser(input)
+}
+
+/// Both assignments as intervals, unless one of them ends before it starts
+/// and so has no sections to compare
+fn intervals(
+    r1: &RangeInclusive<u32>,
+    r2: &RangeInclusive<u32>,
+) -> Option<(Interval<u32>, Interval<u32>)> {
+    Some((
+        Interval::try_from(r1.clone()).ok()?,
+        Interval::try_from(r2.clone()).ok()?,
+    ))
+}
+
+fn either_subset(r1: &RangeInclusive<u32>, r2: &RangeInclusive<u32>) -> bool {
+    intervals(r1, r2).is_some_and(|(i1, i2)| i1.contains_interval(&i2) || i2.contains_interval(&i1))
+}
+
+fn overlap(r1: &RangeInclusive<u32>, r2: &RangeInclusive<u32>) -> bool {
+    intervals(r1, r2).is_some_and(|(i1, i2)| i1.overlaps(&i2))
+}
+
+/// Pairs where one assignment contains the other
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData) -> AocResult<usize> {
+    Ok(input
+        .assignments
+        .iter()
+        .filter(|(r1, r2)| either_subset(r1, r2))
+        .count())
+}
+
+/// Pairs whose assignments overlap
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData) -> AocResult<usize> {
+    Ok(input
+        .assignments
+        .iter()
+        .filter(|(r1, r2)| overlap(r1, r2))
+        .count())
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(4, parse, part1, part2)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+    assert_parser!(
+        parse,
+        input,
+        InputData {
+            assignments: vec![
+                (2..=4, 6..=8),
+                (2..=3, 4..=5),
+                (5..=7, 7..=9),
+                (2..=8, 3..=7),
+                (6..=6, 4..=6),
+                (2..=6, 4..=8),
+            ]
+        }
+    );
+
+    let reversed = parse("5-3,1-2\n4-4,6-2\n").unwrap().1;
+    assert_eq!(part1(&reversed).unwrap(), 0);
+    assert_eq!(part2(&reversed).unwrap(), 0);
+}
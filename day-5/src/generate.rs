@@ -0,0 +1,59 @@
+use utils::generate::{Rng, StdRng};
+
+const STACKS: usize = 9;
+
+/// Writes nine stacks of crates and `size` moves between them
+///
+/// A move never empties a stack, so every stack has a crate on top at
+/// the end.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut heights: Vec<usize> = (0..STACKS).map(|_| rng.gen_range(2..=8)).collect();
+    let tallest = heights.iter().copied().max().unwrap_or_default();
+    let mut lines = Vec::new();
+    for level in (0..tallest).rev() {
+        let slots: Vec<String> = heights
+            .iter()
+            .map(|&height| {
+                if level < height {
+                    format!("[{}]", rng.gen_range('A'..='Z'))
+                } else {
+                    "   ".to_string()
+                }
+            })
+            .collect();
+        lines.push(slots.join(" "));
+    }
+    let labels: Vec<String> = (1..=STACKS).map(|i| format!(" {i} ")).collect();
+    lines.push(labels.join(" "));
+    lines.push(String::new());
+    for _ in 0..size.max(1) {
+        // There are more crates than stacks, so some stack has two
+        let source = loop {
+            let i = rng.gen_range(0..STACKS);
+            if heights[i] > 1 {
+                break i;
+            }
+        };
+        let destination = loop {
+            let i = rng.gen_range(0..STACKS);
+            if i != source {
+                break i;
+            }
+        };
+        let amount = rng.gen_range(1..heights[source]);
+        heights[source] -= amount;
+        heights[destination] += amount;
+        lines.push(format!(
+            "move {amount} from {} to {}",
+            source + 1,
+            destination + 1
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,296 @@
+use itertools::Itertools;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// Moves `amount` crates from the `source` stack to the `destination`
+/// stack, both counted from 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub amount: usize,
+    pub source: usize,
+    pub destination: usize,
+}
+
+/// The stacks of crates, from the bottom up, and the moves of the crane
+#[derive(Debug, PartialEq, Eq)]
+pub struct InputData {
+    pub crates: Vec<Vec<char>>,
+    pub instructions: Vec<Instruction>,
+}
+
+// https://stackoverflow.com/a/64499219/804119
+fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    assert!(!v.is_empty());
+    let len = v[0].len();
+    let mut iters: Vec<_> = v
+        .into_iter()
+        .map(std::iter::IntoIterator::into_iter)
+        .collect();
+    (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .map(|n| n.next().unwrap())
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+/// Parses the drawing of the stacks, a blank line and the moves
+///
+/// # Errors
+///
+/// Fails if the drawing or a move is malformed
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{char, line_ending, one_of, u32},
+        combinator::map,
+        error::context,
+        multi::{many1, separated_list1},
+        sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    };
+    // Character set
+    let alphas: String = ('A'..='Z').collect();
+
+    // Crates
+    let alpha = delimited(char('['), one_of(alphas.as_str()), char(']'));
+    let space = delimited(char(' '), char(' '), char(' '));
+    let line = separated_list1(char(' '), alt((alpha, space)));
+    let crates = separated_list1(line_ending, line);
+
+    // Ignore Stack labels
+    let line = tuple((line_ending, many1(one_of("0123456789 ")), line_ending));
+    let crates = context("crate stacks", terminated(crates, line));
+
+    // Instructions
+    let move_ = preceded(tag("move "), u32);
+    let from = preceded(tag(" from "), u32);
+    let to = preceded(tag(" to "), u32);
+    let instruction = map(tuple((move_, from, to)), |(a, f, t)| Instruction {
+        amount: a as usize,
+        // Change for 0 based indexing
+        source: f as usize - 1,
+        destination: t as usize - 1,
+    });
+    let instruction = context("instruction", instruction);
+    let instructions = context("instructions", separated_list1(line_ending, instruction));
+
+    // Parse everything
+    let body_split = separated_pair(crates, line_ending, instructions);
+    let mut parse = map(body_split, |(crates, instructions)| {
+        // Rotate crates
+        let mut crates = transpose(crates);
+
+        // Reverse and truncate crate stacks
+        for stack in &mut crates {
+            stack.reverse();
+            // Remove space characters
+            if let Some((space, _)) = stack.iter().find_position(|c| c == &&' ') {
+                stack.truncate(space);
+            }
+        }
+
+        InputData {
+            crates,
+            instructions,
+        }
+    });
+    parse(input)
+}
+
+/// Top crates when the crane moves one crate at a time
+///
+/// # Panics
+///
+/// Panics if a move takes more crates than its stack has
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData) -> AocResult<String> {
+    let mut crates = input.crates.clone();
+    for i in &input.instructions {
+        for _ in 0..i.amount {
+            let temp = crates[i.source].pop().unwrap();
+            crates[i.destination].push(temp);
+        }
+    }
+    Ok(crates.iter().map(|cs| cs.last().unwrap()).collect())
+}
+
+/// Top crates when the crane moves all the crates of a move at once
+///
+/// # Panics
+///
+/// Panics if a move takes more crates than its stack has
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData) -> AocResult<String> {
+    let mut crates = input.crates.clone();
+    for i in &input.instructions {
+        let temp = crates[i.source].len() - i.amount;
+        let mut temp = crates[i.source].split_off(temp);
+        crates[i.destination].append(&mut temp);
+    }
+    Ok(crates.iter().map(|cs| cs.last().unwrap()).collect())
+}
+
+/// The crane moving crates, one crate at a time in part 1 and one
+/// instruction at a time in part 2
+struct Crane {
+    crates: Vec<Vec<char>>,
+    instructions: Vec<Instruction>,
+    next: usize,
+    /// Crates of the next instruction that were already moved
+    moved: usize,
+    /// Moves crates together, like the CrateMover 9001 of part 2
+    together: bool,
+    /// Stack that got crates last and how many it got
+    last: Option<(usize, usize)>,
+}
+
+impl viz::Visualize for Crane {
+    fn frame(&self) -> viz::Frame {
+        let height: usize = self.crates.iter().map(Vec::len).sum();
+        let width = self.crates.len() * 4 - 1;
+        let mut frame = viz::Frame::blank(width, height + 1);
+        for (i, stack) in self.crates.iter().enumerate() {
+            let fresh = match self.last {
+                Some((last, count)) if last == i => stack.len() - count,
+                _ => stack.len(),
+            };
+            for (level, c) in stack.iter().enumerate() {
+                let y = height - 1 - level;
+                let color = if level >= fresh {
+                    viz::Color::Yellow
+                } else {
+                    viz::Color::White
+                };
+                frame.paint(i * 4, y, '[', color);
+                frame.paint(i * 4 + 1, y, *c, color);
+                frame.paint(i * 4 + 2, y, ']', color);
+            }
+            if let Some(label) = char::from_digit(i as u32 + 1, 10) {
+                frame.set(i * 4 + 1, height, label);
+            }
+        }
+        frame.caption = match self.instructions.get(self.next) {
+            Some(i) => format!(
+                "move {} from {} to {}, instruction {} of {}",
+                i.amount,
+                i.source + 1,
+                i.destination + 1,
+                self.next + 1,
+                self.instructions.len()
+            ),
+            None => {
+                let tops: String = self.crates.iter().filter_map(|cs| cs.last()).collect();
+                format!("top crates {tops}")
+            }
+        };
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&i) = self.instructions.get(self.next) else {
+            return false;
+        };
+        let amount = if self.together { i.amount } else { 1 };
+        let from = self.crates[i.source].len() - amount;
+        let mut temp = self.crates[i.source].split_off(from);
+        self.crates[i.destination].append(&mut temp);
+        self.last = Some((i.destination, amount));
+        self.moved += amount;
+        if self.moved == i.amount {
+            self.next += 1;
+            self.moved = 0;
+        }
+        true
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn visualize(input: &InputData, part: u8, _params: &Params) -> AocResult<Box<dyn viz::Visualize>> {
+    Ok(Box::new(Crane {
+        crates: input.crates.clone(),
+        instructions: input.instructions.clone(),
+        next: 0,
+        moved: 0,
+        together: part == 2,
+        last: None,
+    }))
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(5, parse, part1, part2).with_visualize(visualize)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+    assert_parser!(
+        parse,
+        input,
+        InputData {
+            crates: vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P'],],
+            instructions: vec![
+                Instruction {
+                    amount: 1,
+                    source: 1,
+                    destination: 0
+                },
+                Instruction {
+                    amount: 3,
+                    source: 0,
+                    destination: 2
+                },
+                Instruction {
+                    amount: 2,
+                    source: 1,
+                    destination: 0
+                },
+                Instruction {
+                    amount: 1,
+                    source: 0,
+                    destination: 1
+                },
+            ]
+        }
+    );
+}
+
+#[test]
+fn test_visualize() {
+    let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3";
+    let (_, input) = parse(input).unwrap();
+    let crane = |part| visualize(&input, part, &Params::default()).unwrap();
+    let frames = viz::frames(crane(1).as_mut(), usize::MAX);
+    assert_eq!(frames.len(), 5);
+    assert_eq!(frames[1].caption, "move 3 from 1 to 3, instruction 2 of 2");
+    assert_eq!(frames[4].caption, "top crates CZ");
+    let lines = [
+        "           ",
+        "           ",
+        "        [Z]",
+        "        [N]",
+        "    [C] [D]",
+        "    [M] [P]",
+        " 1   2   3 ",
+    ];
+    assert_eq!(frames[4].to_string(), lines.join("\n"));
+    assert_eq!(viz::frames(crane(2).as_mut(), usize::MAX).len(), 3);
+}
@@ -0,0 +1,23 @@
+use utils::generate::{Rng, SliceRandom, StdRng};
+
+const MARKER: usize = 14;
+
+/// Writes a signal `size` characters long
+///
+/// The signal is mostly made of a few letters, with a start-of-message
+/// marker of fourteen different ones hidden somewhere in it.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let len = size.max(MARKER);
+    let mut signal: Vec<u8> = (0..len).map(|_| rng.gen_range(b'a'..=b'f')).collect();
+    let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+    marker.shuffle(rng);
+    let at = rng.gen_range(0..=len - MARKER);
+    signal[at..at + MARKER].copy_from_slice(&marker[..MARKER]);
+    String::from_utf8_lossy(&signal).into_owned() + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,51 @@
+use itertools::Itertools;
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// The datastream
+#[derive(Debug)]
+pub struct InputData(pub Vec<char>);
+
+/// Takes every character of the datastream
+#[allow(clippy::unnecessary_wraps)]
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    Ok(("", InputData(input.chars().collect())))
+}
+
+/// Characters read until the first start-of-packet marker
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData) -> AocResult<usize> {
+    const WINDOW_SIZE: usize = 4;
+
+    Ok(input
+        .0
+        .windows(WINDOW_SIZE)
+        .find_position(|a| a.iter().combinations(2).all(|v| v[0] != v[1]))
+        .map_or(0, |(i, _)| i)
+        + WINDOW_SIZE)
+}
+
+/// Characters read until the first start-of-message marker
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData) -> AocResult<usize> {
+    const WINDOW_SIZE: usize = 14;
+
+    Ok(input
+        .0
+        .windows(WINDOW_SIZE)
+        .find_position(|a| a.iter().combinations(2).all(|v| v[0] != v[1]))
+        .map_or(0, |(i, _)| i)
+        + WINDOW_SIZE)
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(6, parse, part1, part2)
+}
+
+aoc_examples!();
@@ -0,0 +1,62 @@
+use std::collections::BTreeSet;
+
+use utils::generate::{Rng, StdRng};
+
+const MAX_DEPTH: usize = 8;
+
+/// Writes the terminal output of exploring a file system of `size` files
+///
+/// The session lists every directory it enters and only changes into
+/// directories it has listed.
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut lines = vec!["$ cd /".to_string()];
+    explore(rng, &mut size.max(1), 0, &mut lines);
+    lines.join("\n") + "\n"
+}
+
+fn explore(rng: &mut StdRng, files_left: &mut usize, depth: usize, lines: &mut Vec<String>) {
+    lines.push("$ ls".to_string());
+    let files = rng.gen_range(1..=4).min(*files_left);
+    *files_left -= files;
+    let dirs = if depth < MAX_DEPTH && *files_left > 0 {
+        rng.gen_range(1..=3)
+    } else {
+        0
+    };
+    let mut names = BTreeSet::new();
+    while names.len() < files + dirs {
+        names.insert(name(rng));
+    }
+    let mut names = names.into_iter();
+    for file in names.by_ref().take(files) {
+        let extension = if rng.gen_bool(0.5) {
+            format!(".{}", name(rng))
+        } else {
+            String::new()
+        };
+        lines.push(format!(
+            "{} {file}{extension}",
+            rng.gen_range(1_000..=300_000)
+        ));
+    }
+    let dirs: Vec<String> = names.collect();
+    for dir in &dirs {
+        lines.push(format!("dir {dir}"));
+    }
+    for dir in &dirs {
+        lines.push(format!("$ cd {dir}"));
+        explore(rng, files_left, depth + 1, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+fn name(rng: &mut StdRng) -> String {
+    let len = rng.gen_range(1..=8);
+    (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
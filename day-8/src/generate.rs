@@ -0,0 +1,16 @@
+use utils::generate::{Rng, StdRng};
+
+/// Writes a square forest of trees `size` trees wide
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(3);
+    let rows: Vec<String> = (0..side)
+        .map(|_| (0..side).map(|_| rng.gen_range('0'..='9')).collect())
+        .collect();
+    rows.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,225 @@
+use ndarray::Array2;
+use utils::grid::{Grid, Pos, NEIGHBORS_4};
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// The height of every tree, by `(row, column)`
+#[derive(Debug, PartialEq, Clone)]
+pub struct InputData(pub Grid<u8>);
+
+/// Parses the grid of tree heights
+///
+/// # Errors
+///
+/// Fails if a line has something other than digits, or if the lines
+/// have different lengths
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::combinator::map;
+    use utils::parsers::digit_grid;
+    map(digit_grid, InputData)(input)
+}
+
+fn mask_ring(input: &InputData) -> Array2<bool> {
+    let (height, width) = input.0.dim();
+    let mut mask = Array2::<bool>::default(input.0.raw_dim());
+    mask.fill(false);
+    mask.row_mut(0).map_mut(|c| *c = true);
+    mask.row_mut(height - 1).map_mut(|c| *c = true);
+    mask.column_mut(0).map_mut(|r| *r = true);
+    mask.column_mut(width - 1).map_mut(|r| *r = true);
+    mask
+}
+
+fn mask_left(input: &InputData) -> Array2<bool> {
+    let mut mask = Array2::<bool>::default(input.0.raw_dim());
+    mask.fill(true);
+    for (i, mut row) in mask.rows_mut().into_iter().enumerate() {
+        let mut max: u8 = *input.0.get((i, 0)).unwrap_or(&0);
+        for (j, col) in row.iter_mut().enumerate().skip(1) {
+            let cur = *input.0.get((i, j)).unwrap_or(&0);
+            *col = cur > max;
+            max = cur.max(max);
+        }
+    }
+    mask
+}
+
+fn mask_right(input: &InputData) -> Array2<bool> {
+    let (_, width) = input.0.dim();
+    let mut mask = Array2::<bool>::default(input.0.raw_dim());
+    mask.fill(true);
+    for (i, mut row) in mask.rows_mut().into_iter().enumerate() {
+        let mut max: u8 = *input.0.get((i, width - 1)).unwrap_or(&0);
+        for (j, col) in row.iter_mut().enumerate().rev().skip(1) {
+            let cur = *input.0.get((i, j)).unwrap_or(&0);
+            *col = cur > max;
+            max = cur.max(max);
+        }
+    }
+    mask
+}
+
+fn mask_down(input: &InputData) -> Array2<bool> {
+    let mut mask = Array2::<bool>::default(input.0.raw_dim());
+    mask.fill(true);
+    for (j, mut col) in mask.columns_mut().into_iter().enumerate() {
+        let mut max: u8 = *input.0.get((0, j)).unwrap_or(&0);
+        for (i, row) in col.iter_mut().enumerate().skip(1) {
+            let cur = *input.0.get((i, j)).unwrap_or(&0);
+            *row = cur > max;
+            max = cur.max(max);
+        }
+    }
+    mask
+}
+
+fn mask_up(input: &InputData) -> Array2<bool> {
+    let (_, width) = input.0.dim();
+    let mut mask = Array2::<bool>::default(input.0.raw_dim());
+    mask.fill(true);
+    for (j, mut col) in mask.columns_mut().into_iter().enumerate() {
+        let mut max: u8 = *input.0.get((width - 1, j)).unwrap_or(&0);
+        for (i, row) in col.iter_mut().enumerate().rev().skip(1) {
+            let cur = *input.0.get((i, j)).unwrap_or(&0);
+            *row = cur > max;
+            max = cur.max(max);
+        }
+    }
+    mask
+}
+
+/// Trees that can be seen from outside the grid
+fn visible(input: &InputData) -> Array2<bool> {
+    let ring = mask_ring(input);
+    let left = mask_left(input);
+    let right = mask_right(input);
+    let up = mask_up(input);
+    let down = mask_down(input);
+    ring | left | right | up | down
+}
+
+/// Trees visible from outside the grid
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData) -> AocResult<usize> {
+    Ok(visible(input).iter().filter(|&&b| b).count())
+}
+
+/// Trees seen from `pos` looking along `delta`, and the last of them
+fn view(input: &InputData, pos: Pos, delta: (isize, isize)) -> (u64, Pos) {
+    let height = input.0[pos];
+    let mut seen = (0, pos);
+    for (next, &tree) in input.0.ray(pos, delta) {
+        seen = (seen.0 + 1, next);
+        if tree >= height {
+            break;
+        }
+    }
+    seen
+}
+
+fn score_tree(input: &InputData, pos: Pos) -> u64 {
+    NEIGHBORS_4
+        .into_iter()
+        .map(|delta| view(input, pos, delta).0)
+        .product()
+}
+
+/// Highest scenic score of any tree
+#[allow(clippy::unnecessary_wraps)]
+pub fn part2(input: &InputData) -> AocResult<u64> {
+    Ok(input
+        .0
+        .cells()
+        .map(|(pos, _)| score_tree(input, pos))
+        .max()
+        .unwrap_or(0))
+}
+
+/// Trees shaded by height, with the hidden ones faded in part 1 and the
+/// view of the most scenic tree in part 2
+#[allow(clippy::unnecessary_wraps, clippy::cast_precision_loss)]
+fn svg(input: &InputData, part: u8, _params: &Params) -> AocResult<svg::Svg> {
+    use utils::{
+        geom::Point2,
+        svg::{Shape, Style, Svg},
+    };
+
+    let (rows, cols) = input.0.dim();
+    let mut svg = Svg::new(Point2::new(0.0, 0.0), cols as f64, rows as f64)
+        .with_background("#0f0f23")
+        .with_title(format!("Day 8 part {part}"));
+    let tree = |height: u8| Style::fill(format!("rgb(0,{},0)", 90 + 15 * u16::from(height)));
+    if part == 1 {
+        let visible = visible(input);
+        for ((row, col), &height) in input.0.indexed_iter() {
+            let style = if visible[(row, col)] {
+                tree(height)
+            } else {
+                tree(height).with_opacity(0.25)
+            };
+            svg.add(&Shape::Cell(Point2::new(col as f64, row as f64)), &style);
+        }
+    } else {
+        svg.grid(&input.0, |&height| Some(tree(height)));
+        let best = input
+            .0
+            .cells()
+            .max_by_key(|&(pos, _)| score_tree(input, pos))
+            .map(|(pos, _)| pos)
+            .unwrap_or_default();
+        let cell = |(row, col): Pos| Point2::new(col, row);
+        for delta in NEIGHBORS_4 {
+            let (_, end) = view(input, best, delta);
+            svg.add(
+                &Shape::path([cell(best), cell(end)]),
+                &Style::stroke("#ffff66", 0.15),
+            );
+        }
+        svg.add(
+            &Shape::Cell(Point2::new(best.1 as f64, best.0 as f64)),
+            &Style::stroke("#ffff66", 0.15),
+        );
+    }
+    Ok(svg)
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new(8, parse, part1, part2).with_svg(svg)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    let input = "30373\n25512\n65332\n33549\n35390";
+    assert_parser!(
+        parse,
+        input,
+        InputData(Grid::from(ndarray::array![
+            [3, 0, 3, 7, 3],
+            [2, 5, 5, 1, 2],
+            [6, 5, 3, 3, 2],
+            [3, 3, 5, 4, 9],
+            [3, 5, 3, 9, 0],
+        ]))
+    );
+}
+
+#[test]
+fn test_svg() {
+    let (_, input) = parse("30373\n25512\n65332\n33549\n35390").unwrap();
+    let picture = svg(&input, 1, &Params::default()).unwrap().to_string();
+    assert_eq!(picture.matches("<rect").count(), 26);
+    assert_eq!(picture.matches(r#"opacity="0.25""#).count(), 4);
+    assert_eq!(view(&input, (3, 2), (-1, 0)), (2, (1, 2)));
+    assert_eq!(view(&input, (3, 2), (0, 1)), (2, (3, 4)));
+    let picture = svg(&input, 2, &Params::default()).unwrap().to_string();
+    assert!(picture.contains(r#"<polyline points="2.5,3.5 2.5,1.5""#));
+    assert!(picture.contains(r#"<rect x="2" y="3" width="1" height="1" fill="none""#));
+}
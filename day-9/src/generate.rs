@@ -0,0 +1,18 @@
+use utils::generate::{Rng, SliceRandom, StdRng};
+
+/// Writes `size` moves of the head of the rope
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let moves: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+            format!("{direction} {}", rng.gen_range(1..=20))
+        })
+        .collect();
+    moves.join("\n") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
@@ -0,0 +1,347 @@
+use std::{collections::HashSet, num::NonZeroUsize};
+
+use itertools::Itertools;
+use lending_iterator::{lending_iterator::constructors::windows_mut, LendingIterator};
+#[allow(clippy::wildcard_imports)]
+use utils::*;
+
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
+/// A direction the head of the rope moves in
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Movement {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A rope of two knots
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rope1 {
+    pub head: (i32, i32),
+    pub tail: (i32, i32),
+}
+
+impl Rope1 {
+    /// Moves the head one step, and returns where the tail ends up
+    pub fn step(&mut self, head_move: Movement) -> (i32, i32) {
+        use Movement::{Down, Left, Right, Up};
+
+        let prev = self.head;
+        match head_move {
+            Up => self.head.1 += 1,
+            Down => self.head.1 -= 1,
+            Left => self.head.0 -= 1,
+            Right => self.head.0 += 1,
+        }
+        if self.head.0.abs_diff(self.tail.0) > 1 || self.head.1.abs_diff(self.tail.1) > 1 {
+            self.tail = prev;
+        }
+        self.tail
+    }
+}
+
+const fn unit(i: i32) -> i32 {
+    if i.is_positive() {
+        1
+    } else {
+        -1
+    }
+}
+
+fn create_grid(base: (i32, i32)) -> Vec<(i32, i32)> {
+    let x_range = (base.0 - 1)..=(base.0 + 1);
+    let y_range = (base.1 - 1)..=(base.1 + 1);
+    x_range.cartesian_product(y_range).collect()
+}
+
+/// A rope of any number of knots, from the head to the tail
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rope2 {
+    pub body: Vec<(i32, i32)>,
+}
+
+impl Rope2 {
+    /// Moves the head one step, and returns where the tail ends up
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rope has no knots
+    pub fn step(&mut self, head_move: Movement) -> (i32, i32) {
+        use Movement::{Down, Left, Right, Up};
+
+        match head_move {
+            Up => self.body[0].1 += 1,
+            Down => self.body[0].1 -= 1,
+            Left => self.body[0].0 -= 1,
+            Right => self.body[0].0 += 1,
+        }
+        // https://github.com/ChristopherBiscardi/advent-of-code/blob/0aa7054ae4c38d348d93e1669a88bcdea5c61d1d/2022/rust/day-09/src/lib.rs#L122-L206
+        // Still trying to figure out what the rules for multi knot ropes are.
+        let mut rope_windows = self.body.windows_mut::<2>();
+        while let Some([ref mut head, ref mut tail]) = rope_windows.next() {
+            let tail_is_connected = create_grid(*head).contains(tail);
+
+            if !tail_is_connected {
+                if head.0 == tail.0 {
+                    tail.1 += unit(head.1 - tail.1);
+                } else if head.1 == tail.1 {
+                    tail.0 += unit(head.0 - tail.0);
+                } else {
+                    // diagonal
+                    let head_options = create_grid(*head);
+                    let tail_options = create_grid(*tail);
+
+                    let maybe_new_tail: Vec<(i32, i32)> = tail_options
+                        .iter()
+                        .filter(|&tuple| head_options.contains(tuple))
+                        .copied()
+                        .collect();
+                    match maybe_new_tail.len() {
+                        2 => {
+                            let head_positions = [
+                                (head.0 - 1, head.1),
+                                (head.0 + 1, head.1),
+                                (head.0, head.1 - 1),
+                                (head.0, head.1 + 1),
+                            ];
+                            let &next = maybe_new_tail
+                                .iter()
+                                .find(|tuple| head_positions.contains(tuple))
+                                .unwrap();
+                            *tail = next;
+                        }
+                        1 => {
+                            *tail = maybe_new_tail[0];
+                        }
+                        _ => {
+                            panic!("unknown tail length");
+                        }
+                    };
+                }
+            }
+        }
+        *self.body.last().unwrap()
+    }
+}
+
+/// The moves of the head, with how many steps each takes
+#[derive(Debug, PartialEq)]
+pub struct InputData {
+    pub movements: Vec<(Movement, u32)>,
+}
+
+/// Parses one move per line, like `R 4`
+///
+/// # Errors
+///
+/// Fails if a line isn't a direction and a number of steps
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::{
+        character::complete::{char, line_ending, one_of, u32},
+        combinator::map,
+        multi::separated_list1,
+        sequence::separated_pair,
+    };
+    use Movement::{Down, Left, Right, Up};
+    let movement = map(one_of("UDLR"), |c| match c {
+        'R' => Right,
+        'L' => Left,
+        'U' => Up,
+        'D' => Down,
+        _ => unreachable!("Nom no worky"),
+    });
+    let line = separated_pair(movement, char(' '), u32);
+    let mut parser = map(separated_list1(line_ending, line), |movements| InputData {
+        movements,
+    });
+    parser(input)
+}
+
+pub const PARAMS: &[Param] = &[Param::new("knots", "10", "length of the rope in part 2")];
+
+/// Positions the tail of a two knot rope visits
+#[allow(clippy::unnecessary_wraps)]
+pub fn part1(input: &InputData, _params: &Params) -> AocResult<usize> {
+    let mut set: HashSet<(i32, i32)> = HashSet::from([(0, 0)]);
+    let mut rope = Rope1 {
+        head: (0, 0),
+        tail: (0, 0),
+    };
+    for (d, n) in &input.movements {
+        for _ in 0..*n {
+            set.insert(rope.step(*d));
+        }
+    }
+    Ok(set.len())
+}
+
+/// Positions the tail of a rope of `knots` knots visits
+///
+/// # Errors
+///
+/// Fails if the `knots` parameter isn't a positive number
+pub fn part2(input: &InputData, params: &Params) -> AocResult<usize> {
+    let knots: NonZeroUsize = params.get("knots")?;
+    let mut set: HashSet<(i32, i32)> = HashSet::from([(0, 0)]);
+    let mut rope = Rope2 {
+        body: vec![(0, 0); knots.get()],
+    };
+    for (d, n) in &input.movements {
+        for _ in 0..*n {
+            set.insert(rope.step(*d));
+        }
+    }
+    Ok(set.len())
+}
+
+/// The rope following the moves of its head one square at a time
+struct RopeViz {
+    rope: Rope2,
+    moves: Vec<Movement>,
+    next: usize,
+    visited: HashSet<(i32, i32)>,
+    /// Lowest and highest corners the head gets to
+    bounds: ((i32, i32), (i32, i32)),
+}
+
+impl RopeViz {
+    fn new(input: &InputData, knots: usize) -> Self {
+        let moves: Vec<Movement> = input
+            .movements
+            .iter()
+            .flat_map(|&(d, n)| std::iter::repeat_n(d, n as usize))
+            .collect();
+        let mut head = Rope2 { body: vec![(0, 0)] };
+        let mut bounds = ((0, 0), (0, 0));
+        for &d in &moves {
+            let (x, y) = head.step(d);
+            bounds = (
+                (bounds.0 .0.min(x), bounds.0 .1.min(y)),
+                (bounds.1 .0.max(x), bounds.1 .1.max(y)),
+            );
+        }
+        Self {
+            rope: Rope2 {
+                body: vec![(0, 0); knots],
+            },
+            moves,
+            next: 0,
+            visited: HashSet::from([(0, 0)]),
+            bounds,
+        }
+    }
+}
+
+impl viz::Visualize for RopeViz {
+    fn frame(&self) -> viz::Frame {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds;
+        let width = min_x.abs_diff(max_x) as usize + 1;
+        let height = min_y.abs_diff(max_y) as usize + 1;
+        let mut frame = viz::Frame::new(width, height).with_caption(format!(
+            "move {} of {}, the tail visited {} positions",
+            self.next,
+            self.moves.len(),
+            self.visited.len()
+        ));
+        // Up is positive, so rows count down from the top
+        let cell = |(x, y): (i32, i32)| (x.abs_diff(min_x) as usize, y.abs_diff(max_y) as usize);
+        for &pos in &self.visited {
+            let (x, y) = cell(pos);
+            frame.paint(x, y, '#', viz::Color::Grey);
+        }
+        let (x, y) = cell((0, 0));
+        frame.set(x, y, 's');
+        let knots = self.rope.body.len();
+        for (i, &pos) in self.rope.body.iter().enumerate().rev() {
+            let (x, y) = cell(pos);
+            let c = match i {
+                0 => 'H',
+                1 if knots == 2 => 'T',
+                _ => char::from_digit(i as u32, 10).unwrap_or('*'),
+            };
+            frame.paint(
+                x,
+                y,
+                c,
+                if i == 0 {
+                    viz::Color::Red
+                } else {
+                    viz::Color::Yellow
+                },
+            );
+        }
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&d) = self.moves.get(self.next) else {
+            return false;
+        };
+        self.visited.insert(self.rope.step(d));
+        self.next += 1;
+        true
+    }
+}
+
+fn visualize(input: &InputData, part: u8, params: &Params) -> AocResult<Box<dyn viz::Visualize>> {
+    let knots = if part == 2 {
+        params.get::<NonZeroUsize>("knots")?.get()
+    } else {
+        2
+    };
+    Ok(Box::new(RopeViz::new(input, knots)))
+}
+
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::with_params(9, parse, part1, part2, PARAMS).with_visualize(visualize)
+}
+
+aoc_examples!();
+
+#[test]
+fn test() {
+    use Movement::*;
+    let input1 = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+    assert_parser!(
+        parse,
+        input1,
+        InputData {
+            movements: vec![
+                (Right, 4),
+                (Up, 4),
+                (Left, 3),
+                (Down, 1),
+                (Right, 4),
+                (Down, 1),
+                (Left, 5),
+                (Right, 2),
+            ]
+        }
+    );
+}
+
+#[test]
+fn test_visualize() {
+    let (_, input) = parse("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap();
+    let frames = viz::frames(&mut RopeViz::new(&input, 2), usize::MAX);
+    assert_eq!(frames.len(), 25);
+    assert_eq!(
+        frames[24].to_string(),
+        "\
+..##..
+...##.
+.TH##.
+....#.
+s###.."
+    );
+    assert_eq!(
+        frames[24].caption,
+        "move 24 of 24, the tail visited 13 positions"
+    );
+}
@@ -0,0 +1,142 @@
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Display};
+
+/// A puzzle answer, in the shape it is submitted in
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Lines of a drawing, like the CRT output of day 10
+    Picture(Vec<String>),
+    /// Several values, printed separated by commas
+    Tuple(Vec<Answer>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Picture(lines) => write!(f, "{}", lines.join("\n")),
+            Self::Tuple(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Int(value) => serializer.serialize_i128(*value),
+            Self::Text(text) => serializer.serialize_str(text),
+            Self::Picture(lines) => lines.serialize(serializer),
+            Self::Tuple(values) => values.serialize(serializer),
+        }
+    }
+}
+
+/// Values a part can return as its answer
+///
+/// Answers are compared and printed through [`Answer`], so `24000_u64`
+/// and `24000` are the same answer and strings print without quotes.
+pub trait AocAnswer {
+    fn answer(&self) -> Answer;
+
+    /// More about how the answer was found, shown with `--verbose`
+    fn details(&self) -> Option<String> {
+        None
+    }
+}
+
+impl AocAnswer for Answer {
+    fn answer(&self) -> Answer {
+        self.clone()
+    }
+}
+
+macro_rules! int_answer {
+    ($($int:ty),*) => {
+        $(impl AocAnswer for $int {
+            fn answer(&self) -> Answer {
+                i128::try_from(*self).map_or_else(|_| Answer::Text(self.to_string()), Answer::Int)
+            }
+        })*
+    };
+}
+
+int_answer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl AocAnswer for str {
+    fn answer(&self) -> Answer {
+        Answer::Text(self.to_string())
+    }
+}
+
+impl AocAnswer for String {
+    fn answer(&self) -> Answer {
+        Answer::Text(self.clone())
+    }
+}
+
+impl AocAnswer for char {
+    fn answer(&self) -> Answer {
+        Answer::Text(self.to_string())
+    }
+}
+
+/// Lines of text are a picture
+impl<S: AsRef<str>> AocAnswer for Vec<S> {
+    fn answer(&self) -> Answer {
+        Answer::Picture(self.iter().map(|l| l.as_ref().to_string()).collect())
+    }
+}
+
+impl<T: AocAnswer + ?Sized> AocAnswer for &T {
+    fn answer(&self) -> Answer {
+        (**self).answer()
+    }
+
+    fn details(&self) -> Option<String> {
+        (**self).details()
+    }
+}
+
+/// Placeholder for parts that aren't solved yet
+impl AocAnswer for () {
+    fn answer(&self) -> Answer {
+        Answer::Tuple(Vec::new())
+    }
+}
+
+impl<A: AocAnswer, B: AocAnswer> AocAnswer for (A, B) {
+    fn answer(&self) -> Answer {
+        Answer::Tuple(vec![self.0.answer(), self.1.answer()])
+    }
+}
+
+impl<A: AocAnswer, B: AocAnswer, C: AocAnswer> AocAnswer for (A, B, C) {
+    fn answer(&self) -> Answer {
+        Answer::Tuple(vec![self.0.answer(), self.1.answer(), self.2.answer()])
+    }
+}
+
+#[test]
+fn test_answer() {
+    assert_eq!(24_000_u64.answer(), 24_000.answer());
+    assert_eq!("CMZ".answer(), String::from("CMZ").answer());
+    assert_eq!("CMZ".answer().to_string(), "CMZ");
+    assert_eq!(u128::MAX.answer().to_string(), u128::MAX.to_string());
+    assert_eq!((2, -3_i64).answer().to_string(), "2,-3");
+    assert_eq!(vec!["#.", ".#"].answer().to_string(), "#.\n.#");
+    assert_eq!(
+        serde_json::to_value(('a', vec!["#."]).answer()).unwrap(),
+        serde_json::json!(["a", ["#."]])
+    );
+}
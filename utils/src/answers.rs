@@ -0,0 +1,170 @@
+use anyhow::Context;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    path::Path,
+};
+
+use crate::{report::Report, AocResult};
+
+/// Name of the file holding a day's known answers
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers for a day, read from its `answers.toml`
+///
+/// ```toml
+/// part1 = 24000
+/// part2 = "CMZ"
+/// ```
+///
+/// Answers belong to personal inputs, so the file is kept out of git.
+/// Example sidecars can also set the parameters the example needs:
+///
+/// ```toml
+/// part1 = 26
+///
+/// [params]
+/// row = 10
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Answers {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+}
+
+impl Answers {
+    /// Reads the answers file of the day in `day_dir`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file can't be read or isn't valid
+    pub fn load(day_dir: &Path) -> AocResult<Self> {
+        Self::load_file(&day_dir.join(ANSWERS_FILE))
+    }
+
+    /// Reads answers from a file of the same shape, like an example's sidecar
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file can't be read or isn't valid
+    pub fn load_file(path: &Path) -> AocResult<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Unable to read {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("Invalid answers in {}", path.display()))
+    }
+
+    /// The expected answer for `part`, as text
+    #[must_use]
+    pub fn expected(&self, part: u8) -> Option<String> {
+        let value = match part {
+            1 => self.part1.as_ref()?,
+            2 => self.part2.as_ref()?,
+            _ => return None,
+        };
+        Some(toml_text(value))
+    }
+
+    /// Parameters to run with, as `(name, value)` pairs
+    #[must_use]
+    pub fn params(&self) -> Vec<(String, String)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (name.clone(), toml_text(value)))
+            .collect()
+    }
+
+    /// Compares the answers in `report` with the known ones
+    ///
+    /// Parts without an answer on either side are skipped.
+    #[must_use]
+    pub fn check(&self, report: &Report) -> Vec<Mismatch> {
+        report
+            .parts
+            .iter()
+            .filter_map(|part| {
+                let expected = self.expected(part.part)?;
+                let actual = part.answer.as_ref()?.to_string();
+                (expected != actual).then_some(Mismatch {
+                    day: report.day,
+                    part: part.part,
+                    expected,
+                    actual,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A value from `answers.toml` written out the way [`crate::Answer`] displays
+///
+/// Arrays are tuples, so `[3, 4]` matches the answer `3,4`.
+fn toml_text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(answer) => answer.trim_matches(['\r', '\n']).to_string(),
+        toml::Value::Array(values) => values.iter().map(toml_text).collect::<Vec<_>>().join(","),
+        value => value.to_string(),
+    }
+}
+
+/// A part whose answer differs from the known one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} part {} does not match {ANSWERS_FILE}",
+            self.day, self.part
+        )?;
+        let expected: Vec<_> = self.expected.lines().collect();
+        let actual: Vec<_> = self.actual.lines().collect();
+        for i in 0..expected.len().max(actual.len()) {
+            match (expected.get(i), actual.get(i)) {
+                (Some(e), Some(a)) if e == a => write!(f, "\n  {e}")?,
+                (e, a) => {
+                    if let Some(e) = e {
+                        write!(f, "\n- {e}")?;
+                    }
+                    if let Some(a) = a {
+                        write!(f, "\n+ {a}")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+#[test]
+fn test_answers() {
+    let answers: Answers =
+        toml::from_str("part1 = 24000\npart2 = \"\"\"\n#..\n.#.\n\"\"\"").unwrap();
+    assert_eq!(answers.expected(1).as_deref(), Some("24000"));
+
+    let mut report = Report::new(1);
+    report.part(1, Ok(24_000));
+    report.part(2, Ok(vec!["#..", "..#"]));
+    let mismatches = answers.check(&report);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(
+        mismatches[0].to_string(),
+        "day 1 part 2 does not match answers.toml\n  #..\n- .#.\n+ ..#"
+    );
+
+    report.parts.pop();
+    assert!(answers.check(&report).is_empty());
+
+    let answers: Answers = toml::from_str("part1 = 26\n[params]\nrow = 10").unwrap();
+    assert_eq!(answers.params(), [("row".to_string(), "10".to_string())]);
+}
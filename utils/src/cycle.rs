@@ -0,0 +1,283 @@
+use num_traits::PrimInt;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Where a sequence of states starts repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Step of the first state that comes back
+    pub start: usize,
+    /// Steps it takes to come back
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step whose state is the same as the one of step `n`
+    #[must_use]
+    pub const fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Brent's cycle detection on `start`, `next(start)`, `next(next(start))`...
+///
+/// Only two states are kept at a time and `next` is called fewer times
+/// than with [`floyd`]. It never returns if the states never repeat.
+pub fn brent<T, F>(start: &T, mut next: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // Find the period by moving the tortoise to the hare every power of two
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = next(start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = next(&hare);
+        period += 1;
+    }
+    // Then the start, with the hare a period ahead of the tortoise
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..period {
+        hare = next(&hare);
+    }
+    let mut first = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        first += 1;
+    }
+    Cycle {
+        start: first,
+        period,
+    }
+}
+
+/// Floyd's cycle detection on `start`, `next(start)`, `next(next(start))`...
+///
+/// Only two states are kept at a time. It never returns if the states
+/// never repeat.
+pub fn floyd<T, F>(start: &T, mut next: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // The hare goes twice as fast, so they meet somewhere in the cycle
+    let mut tortoise = next(start);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        hare = next(&hare);
+    }
+    let mut tortoise = start.clone();
+    let mut first = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        first += 1;
+    }
+    let mut period = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        period += 1;
+    }
+    Cycle {
+        start: first,
+        period,
+    }
+}
+
+/// The step every key was first seen at, to notice a state coming back
+/// after a single pass
+///
+/// Keys can be a part of the state, like positions without the counters
+/// that keep growing.
+#[derive(Debug, Clone)]
+pub struct History<K> {
+    seen: HashMap<K, usize>,
+}
+
+impl<K> Default for History<K> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> History<K> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the key of the next step, starting at step 0
+    ///
+    /// Returns the cycle once a key was seen before, which is when the
+    /// history should stop being recorded.
+    pub fn record(&mut self, key: K) -> Option<Cycle> {
+        let step = self.seen.len();
+        match self.seen.entry(key) {
+            Entry::Occupied(first) => Some(Cycle {
+                start: *first.get(),
+                period: step - first.get(),
+            }),
+            Entry::Vacant(first) => {
+                first.insert(step);
+                None
+            }
+        }
+    }
+}
+
+/// The states of a simulation up to a step, or up to the first time one
+/// came back
+#[derive(Debug, Clone)]
+pub struct Timeline<T> {
+    states: Vec<T>,
+    cycle: Option<Cycle>,
+}
+
+impl<T> Timeline<T> {
+    /// The cycle, unless the simulation got to its last step first
+    #[must_use]
+    pub const fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    fn step(&self, n: usize) -> usize {
+        self.cycle.map_or(n, |cycle| cycle.equivalent(n))
+    }
+
+    /// The state at step `n`, or the one it repeats
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is past the steps the timeline was simulated for
+    #[must_use]
+    pub fn state(&self, n: usize) -> &T {
+        &self.states[self.step(n)]
+    }
+
+    /// The value at step `n` of something growing by as much every cycle,
+    /// like a counter kept in the states but left out of their keys
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is past the steps the timeline was simulated for, or
+    /// if the value doesn't fit in `V`
+    pub fn extrapolate<V, F>(&self, n: usize, mut value: F) -> V
+    where
+        V: PrimInt,
+        F: FnMut(&T) -> V,
+    {
+        let step = self.step(n);
+        let at_step = value(&self.states[step]);
+        match self.cycle {
+            Some(Cycle { start, period }) if step != n => {
+                let cycles = V::from((n - step) / period).expect("too many cycles");
+                let growth = value(&self.states[start + period]) - value(&self.states[start]);
+                at_step + cycles * growth
+            }
+            _ => at_step,
+        }
+    }
+}
+
+/// Runs `next` from `start` until step `n`, or until the key of a state
+/// was seen before, so the states of steps past it can be found without
+/// simulating them
+pub fn simulate<T, K, F, FK>(start: T, n: usize, mut next: F, mut key: FK) -> Timeline<T>
+where
+    K: Eq + Hash,
+    F: FnMut(&T) -> T,
+    FK: FnMut(&T) -> K,
+{
+    let mut history = History::new();
+    history.record(key(&start));
+    let mut states = vec![start];
+    let mut cycle = None;
+    while states.len() <= n && cycle.is_none() {
+        let state = next(&states[states.len() - 1]);
+        cycle = history.record(key(&state));
+        states.push(state);
+    }
+    Timeline { states, cycle }
+}
+
+/// The state of a simulation at step `n`, skipping whole cycles once a
+/// state comes back
+///
+/// ```
+/// use utils::cycle::fast_forward;
+///
+/// let dial = fast_forward(7, 1_000_000_000_001, |d| (d + 3) % 10, |&d| d);
+/// assert_eq!(dial, 0);
+/// ```
+pub fn fast_forward<T, K, F, FK>(start: T, n: usize, next: F, key: FK) -> T
+where
+    T: Clone,
+    K: Eq + Hash,
+    F: FnMut(&T) -> T,
+    FK: FnMut(&T) -> K,
+{
+    simulate(start, n, next, key).state(n).clone()
+}
+
+#[test]
+fn test_cycle() {
+    // 0 1 2 3 4 5 2 3 4 5 2...
+    let next = |&x: &u32| if x < 5 { x + 1 } else { 2 };
+    let expected = Cycle {
+        start: 2,
+        period: 4,
+    };
+    assert_eq!(brent(&0, next), expected);
+    assert_eq!(floyd(&0, next), expected);
+    assert_eq!(brent(&2, next).start, 0);
+    assert_eq!(expected.equivalent(1), 1);
+    assert_eq!(expected.equivalent(11), 3);
+
+    let mut history = History::new();
+    let mut x = 0;
+    let cycle = loop {
+        if let Some(cycle) = history.record(x) {
+            break cycle;
+        }
+        x = next(&x);
+    };
+    assert_eq!(cycle, expected);
+
+    let square = |&x: &u64| (x * x + 1) % 255;
+    let timeline = simulate(3, usize::MAX, square, |&x| x);
+    assert_eq!(timeline.cycle(), Some(brent(&3, square)));
+    assert_eq!(timeline.cycle(), Some(floyd(&3, square)));
+
+    // Counting steps alongside a state that repeats
+    let counted = |&(x, count): &(u32, u64)| (next(&x), count + 1);
+    let timeline = simulate((0, 0), 1_000_000, counted, |&(x, _)| x);
+    assert_eq!(timeline.states.len(), 7);
+    assert_eq!(timeline.state(1_000_000), &(4, 4));
+    assert_eq!(
+        timeline.extrapolate(1_000_000, |&(_, count)| count),
+        1_000_000
+    );
+    assert_eq!(timeline.extrapolate(4, |&(_, count)| count), 4);
+    let short = simulate((0, 0), 3, counted, |&(x, _)| x);
+    assert_eq!((short.cycle(), short.states.len()), (None, 4));
+    assert_eq!(fast_forward(0, 1_000_001, next, |&x| x), 5);
+}
@@ -0,0 +1,195 @@
+use nom::{
+    error::{VerboseError, VerboseErrorKind},
+    Finish, Offset,
+};
+use std::fmt::{self, Display};
+
+use crate::ParseResult;
+
+/// A position in the puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Byte offset from the start of the input
+    pub offset: usize,
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column in characters, starting at 1
+    pub column: usize,
+}
+
+impl Location {
+    /// Finds where `remaining` starts inside of `input`
+    ///
+    /// `remaining` has to be a slice of `input`, which is what nom
+    /// hands back for the unparsed part of the input.
+    #[must_use]
+    pub fn new(input: &str, remaining: &str) -> Self {
+        let offset = input.offset(remaining).min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            offset,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Parse failure pointing at the offending spot of the puzzle input
+///
+/// Owns everything it needs for its message, so it can be passed
+/// around as an [`anyhow::Error`] after the input is gone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub location: Location,
+    pub message: String,
+    /// The full line of input the error is on
+    pub source_line: String,
+    /// Contexts the parser was in, innermost first
+    pub contexts: Vec<(&'static str, Location)>,
+}
+
+impl ParseError {
+    /// Creates an error for `remaining` with a custom message
+    #[must_use]
+    pub fn at(input: &str, remaining: &str, message: impl Into<String>) -> Self {
+        let location = Location::new(input, remaining);
+        let line_start = input[..location.offset].rfind('\n').map_or(0, |i| i + 1);
+        let source_line = input[line_start..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        Self {
+            location,
+            message: message.into(),
+            source_line,
+            contexts: Vec::new(),
+        }
+    }
+
+    /// Converts nom's error trace for `input` into a located error
+    #[must_use]
+    pub fn from_verbose(input: &str, error: &VerboseError<&str>) -> Self {
+        let Some((remaining, kind)) = error.errors.first() else {
+            return Self::at(input, input, "unknown parse error");
+        };
+        let message = match kind {
+            VerboseErrorKind::Char(c) => format!("expected '{}'", c.escape_default()),
+            VerboseErrorKind::Context(context) => format!("expected {context}"),
+            VerboseErrorKind::Nom(kind) => format!("failed at {kind:?}"),
+        };
+        let mut parse_error = Self::at(input, remaining, message);
+        parse_error.contexts = error
+            .errors
+            .iter()
+            .skip(1)
+            .filter_map(|(remaining, kind)| match kind {
+                VerboseErrorKind::Context(context) => {
+                    Some((*context, Location::new(input, remaining)))
+                }
+                _ => None,
+            })
+            .collect();
+        parse_error
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Location { line, column, .. } = self.location;
+        let gutter = line.to_string().len();
+        let source_line = self.source_line.trim_end_matches('\r');
+        writeln!(f, "{} at {}", self.message, self.location)?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{line} | {source_line}")?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^")?;
+        for (context, location) in &self.contexts {
+            write!(f, "\n  while parsing {context} starting at {location}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Finishes a parser run, turning a failure into a [`ParseError`]
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the parser failed
+pub fn finish<'a, T>(
+    input: &'a str,
+    result: ParseResult<'a, T>,
+) -> Result<(&'a str, T), ParseError> {
+    result
+        .finish()
+        .map_err(|e| ParseError::from_verbose(input, &e))
+}
+
+/// Checks that a parser consumed everything except trailing whitespace
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] pointing at the first unconsumed input
+pub fn check_consumed(input: &str, remaining: &str) -> Result<(), ParseError> {
+    let unconsumed = remaining.trim_start();
+    if unconsumed.trim_end().is_empty() {
+        Ok(())
+    } else {
+        Err(ParseError::at(
+            input,
+            unconsumed,
+            format!(
+                "parsing stopped with {} bytes of input left",
+                unconsumed.trim_end().len()
+            ),
+        ))
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{i64, line_ending},
+        error::context,
+        multi::separated_list1,
+        sequence::preceded,
+    };
+    let input = "x=1\nx=2\nx=y\n";
+    let result = separated_list1(line_ending, context("value", preceded(tag("x="), i64)))(input);
+    let (remaining, _) = finish(input, result).unwrap();
+    let location = Location::new(input, remaining);
+    assert_eq!((location.line, location.column), (2, 4));
+    let error = check_consumed(input, remaining).unwrap_err();
+    assert_eq!((error.location.line, error.location.column), (3, 1));
+    assert_eq!(error.source_line, "x=y");
+    assert!(check_consumed(input, "\n \r\n").is_ok());
+
+    let result = context(
+        "values",
+        separated_list1(line_ending, context("value", preceded(tag("x="), i64))),
+    )(&input[8..]);
+    let error = finish(input, result).unwrap_err();
+    assert_eq!(
+        error.location,
+        Location {
+            offset: 10,
+            line: 3,
+            column: 3
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "failed at Digit at line 3, column 3\n  |\n3 | x=y\n  |   ^\n  \
+         while parsing value starting at line 3, column 1\n  \
+         while parsing values starting at line 3, column 1"
+    );
+}
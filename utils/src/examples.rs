@@ -0,0 +1,152 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::Answers, solution, RunOptions, Solution, SolutionPart};
+
+/// Writes a test for every example of the day, to be called from its `build.rs`
+///
+/// Each `examples/<name>.txt` with a sidecar `examples/<name>.toml`
+/// gets one test per part the sidecar has an answer for. The sidecar
+/// has the same shape as `answers.toml`:
+///
+/// ```toml
+/// part1 = 24000
+/// part2 = 45000
+///
+/// [params]
+/// top = 3
+/// ```
+///
+/// The tests are written to `$OUT_DIR/examples.rs` and pulled into the
+/// crate with [`aoc_examples!`](crate::aoc_examples).
+///
+/// # Panics
+///
+/// Panics if the examples can't be read or a sidecar is invalid, which
+/// fails the build with the reason.
+pub fn generate_tests() {
+    let day_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").expect("run by cargo"));
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").expect("run by cargo"));
+    let examples = day_dir.join("examples");
+    if examples.is_dir() {
+        println!("cargo:rerun-if-changed={}", examples.display());
+    }
+    let tests = example_tests(&examples).unwrap_or_else(|e| panic!("{e:#}"));
+    std::fs::write(out_dir.join("examples.rs"), tests).expect("unable to write the example tests");
+}
+
+fn example_tests(examples: &Path) -> crate::AocResult<String> {
+    let mut tests = String::new();
+    if !examples.is_dir() {
+        return Ok(tests);
+    }
+    let mut inputs: Vec<PathBuf> = std::fs::read_dir(examples)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<std::io::Result<_>>()?;
+    inputs.retain(|path| path.extension().is_some_and(|e| e == "txt"));
+    inputs.sort();
+    for input in inputs {
+        let sidecar = input.with_extension("toml");
+        if !sidecar.exists() {
+            continue;
+        }
+        let answers = Answers::load_file(&sidecar)?;
+        let name: String = input
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let params: Vec<String> = answers
+            .params()
+            .iter()
+            .map(|(name, value)| format!("({name:?}, {value:?})"))
+            .collect();
+        for part in [1, 2] {
+            if let Some(expected) = answers.expected(part) {
+                writeln!(
+                    tests,
+                    "#[test]\nfn example_{name}_part{part}() {{\n    \
+                     ::utils::examples::check(&super::solution(), \
+                     include_str!({input:?}), {part}, {expected:?}, &[{params}]);\n}}\n",
+                    input = input.display().to_string(),
+                    params = params.join(", "),
+                )?;
+            }
+        }
+    }
+    Ok(tests)
+}
+
+/// Runs one part of `solution` on an example and checks its answer
+///
+/// The input is parsed in strict mode, so an example the parser stops
+/// early on fails too.
+///
+/// # Panics
+///
+/// Panics if the solution fails or gives a different answer
+pub fn check<S: Solution>(
+    solution: &S,
+    input: &str,
+    part: u8,
+    expected: &str,
+    params: &[(&str, &str)],
+) {
+    let options = RunOptions {
+        part: if part == 1 {
+            SolutionPart::PartOne
+        } else {
+            SolutionPart::PartTwo
+        },
+        strict: true,
+        params: params
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect(),
+        ..RunOptions::default()
+    };
+    let mut report = solution::run(solution, input, &options);
+    if let Some(e) = report.take_error() {
+        panic!("part {part} failed: {e:#}");
+    }
+    let answer = report
+        .parts
+        .iter()
+        .find_map(|p| p.answer.as_ref())
+        .expect("the part ran");
+    assert_eq!(answer.to_string(), expected);
+}
+
+/// Includes the tests written by [`examples::generate_tests`](crate::examples::generate_tests)
+#[macro_export]
+macro_rules! aoc_examples {
+    () => {
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+#[test]
+fn test_example_tests() {
+    let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("small.txt"), "1\n2\n").unwrap();
+    std::fs::write(
+        dir.join("small.toml"),
+        "part2 = \"CMZ\"\n[params]\nrow = 10",
+    )
+    .unwrap();
+    std::fs::write(dir.join("no-answers.txt"), "3\n").unwrap();
+    let tests = example_tests(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(tests.matches("#[test]").count(), 1);
+    assert!(tests.contains("fn example_small_part2()"));
+    assert!(tests.contains(", 2, \"CMZ\", &[(\"row\", \"10\")]);"));
+}
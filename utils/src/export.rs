@@ -0,0 +1,389 @@
+use anyhow::Context;
+use clap::{Args, ValueEnum};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    viz::{Color, Frame, Visualize},
+    AocResult,
+};
+
+/// Colors frames are drawn with in images
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Palette {
+    /// Light cells on a dark blue background, like the puzzle pages
+    #[default]
+    Dark,
+    /// Dark cells on a white background, for printing
+    Light,
+}
+
+impl Palette {
+    /// Color of a cell in this palette
+    ///
+    /// Cells without a color are background when they hold `.` or a
+    /// space, and foreground otherwise.
+    #[must_use]
+    pub const fn rgb(self, (c, color): (char, Option<Color>)) -> [u8; 3] {
+        let dark = matches!(self, Self::Dark);
+        match color {
+            None if c == '.' || c == ' ' => {
+                if dark {
+                    [0x0f, 0x0f, 0x23]
+                } else {
+                    [0xff, 0xff, 0xff]
+                }
+            }
+            None => {
+                if dark {
+                    [0xcc, 0xcc, 0xcc]
+                } else {
+                    [0x20, 0x20, 0x20]
+                }
+            }
+            Some(Color::Black) => [0x00, 0x00, 0x00],
+            Some(Color::Red) => [0xe0, 0x40, 0x40],
+            Some(Color::Green) => [0x00, 0xcc, 0x00],
+            Some(Color::Yellow) if dark => [0xff, 0xff, 0x66],
+            Some(Color::Yellow) => [0xcc, 0x99, 0x00],
+            Some(Color::Blue) => [0x40, 0x70, 0xe0],
+            Some(Color::Magenta) => [0xcc, 0x40, 0xcc],
+            Some(Color::Cyan) => [0x00, 0xbb, 0xcc],
+            Some(Color::White) if dark => [0xff, 0xff, 0xff],
+            Some(Color::White) => [0xaa, 0xaa, 0xaa],
+            Some(Color::Grey) => [0x66, 0x66, 0x66],
+            Some(Color::Rgb(r, g, b)) => [r, g, b],
+        }
+    }
+}
+
+/// Options deciding how frames become images
+#[derive(Debug, Clone, Args)]
+// Keeps the doc comment above out of the command's own `--help` text
+#[command(about = None, long_about = None)]
+pub struct ExportOptions {
+    /// Size of a cell in pixels
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+    pub scale: u32,
+
+    /// Colors to draw the cells with
+    #[arg(long, value_enum, default_value_t)]
+    pub palette: Palette,
+
+    /// Keep one frame out of this many, the last frame is always kept
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub frame_skip: u32,
+
+    /// How long each frame of a GIF is shown, in milliseconds
+    #[arg(long, default_value_t = 50)]
+    pub frame_delay: u16,
+
+    /// Stop after writing this many frames
+    #[arg(long, default_value_t = 1000)]
+    pub max_frames: usize,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            scale: 4,
+            palette: Palette::Dark,
+            frame_skip: 1,
+            frame_delay: 50,
+            max_frames: 1000,
+        }
+    }
+}
+
+/// A frame drawn as RGB pixels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    /// Red, green and blue of each pixel, row by row
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Draws `frame` on a canvas of `columns` by `rows` cells
+    ///
+    /// Parts of the frame outside of the canvas are cut off and parts of
+    /// the canvas outside of the frame are background.
+    fn render(frame: &Frame, columns: usize, rows: usize, options: &ExportOptions) -> Self {
+        let scale = options.scale as usize;
+        let (width, height) = (columns * scale, rows * scale);
+        let background = options.palette.rgb(('.', None));
+        let mut pixels = background.repeat(width * height);
+        for y in 0..rows.min(frame.height()) {
+            for x in 0..columns.min(frame.width()) {
+                let rgb = options.palette.rgb(frame.get(x, y).unwrap_or((' ', None)));
+                for row in y * scale..(y + 1) * scale {
+                    let start = (row * width + x * scale) * 3;
+                    for pixel in pixels[start..start + scale * 3].chunks_mut(3) {
+                        pixel.copy_from_slice(&rgb);
+                    }
+                }
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Colors of the pixels as indices into a palette of at most 256 colors
+    ///
+    /// `None` when the image has more colors than that.
+    fn indexed(&self) -> Option<(Vec<u8>, Vec<u8>)> {
+        let mut palette = Vec::new();
+        let mut indices = HashMap::new();
+        let mut buffer = Vec::with_capacity(self.width * self.height);
+        for rgb in self.pixels.chunks(3) {
+            let index = match indices.get(rgb) {
+                Some(&index) => index,
+                None => {
+                    let index = u8::try_from(indices.len()).ok()?;
+                    indices.insert(rgb, index);
+                    palette.extend_from_slice(rgb);
+                    index
+                }
+            };
+            buffer.push(index);
+        }
+        Some((buffer, palette))
+    }
+}
+
+/// Draws a single frame as an image
+#[must_use]
+pub fn render(frame: &Frame, options: &ExportOptions) -> Image {
+    Image::render(frame, frame.width(), frame.height(), options)
+}
+
+/// Writes a single frame to a PNG file
+///
+/// # Errors
+///
+/// Fails if the file can't be written
+pub fn write_png(path: &Path, frame: &Frame, options: &ExportOptions) -> AocResult<()> {
+    let image = render(frame, options);
+    let file =
+        File::create(path).with_context(|| format!("Unable to create {}", path.display()))?;
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(file),
+        u32::try_from(image.width)?,
+        u32::try_from(image.height)?,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&image.pixels)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Path of the `index`th image of a PNG sequence, like `sand-0003.png`
+#[must_use]
+pub fn sequence_path(path: &Path, index: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{stem}-{index:04}.png"))
+}
+
+/// Writes frames to an animated GIF that loops forever
+struct GifWriter {
+    encoder: gif::Encoder<BufWriter<File>>,
+    columns: usize,
+    rows: usize,
+}
+
+impl GifWriter {
+    /// Creates the file, sized to fit `first` and the frames after it
+    fn create(path: &Path, first: &Frame, options: &ExportOptions) -> AocResult<Self> {
+        let scale = options.scale as usize;
+        let too_big = || {
+            format!(
+                "Frames of {}x{} cells are too big for a GIF",
+                first.width(),
+                first.height()
+            )
+        };
+        let width = u16::try_from(first.width() * scale).with_context(too_big)?;
+        let height = u16::try_from(first.height() * scale).with_context(too_big)?;
+        let file =
+            File::create(path).with_context(|| format!("Unable to create {}", path.display()))?;
+        let mut encoder = gif::Encoder::new(BufWriter::new(file), width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        Ok(Self {
+            encoder,
+            columns: first.width(),
+            rows: first.height(),
+        })
+    }
+
+    fn write(&mut self, frame: &Frame, options: &ExportOptions) -> AocResult<()> {
+        let image = Image::render(frame, self.columns, self.rows, options);
+        // Both sizes fit in a u16, the encoder was created with them
+        let (width, height) = (image.width as u16, image.height as u16);
+        let mut gif_frame = match image.indexed() {
+            Some((buffer, palette)) => gif::Frame {
+                width,
+                height,
+                buffer: Cow::Owned(buffer),
+                palette: Some(palette),
+                ..gif::Frame::default()
+            },
+            None => gif::Frame::from_rgb(width, height, &image.pixels),
+        };
+        // GIF delays are in hundredths of a second
+        gif_frame.delay = options.frame_delay.div_ceil(10);
+        self.encoder.write_frame(&gif_frame)?;
+        Ok(())
+    }
+}
+
+/// Runs a simulation, handing every frame `options` keeps to `write`
+///
+/// Returns the number of frames written.
+fn sample(
+    viz: &mut dyn Visualize,
+    options: &ExportOptions,
+    mut write: impl FnMut(usize, &Frame) -> AocResult<()>,
+) -> AocResult<usize> {
+    let skip = options.frame_skip.max(1) as usize;
+    let mut written = 0;
+    let mut step = 0;
+    let mut last_written = true;
+    while written < options.max_frames {
+        if step % skip == 0 {
+            write(written, &viz.frame())?;
+            written += 1;
+            last_written = true;
+        } else {
+            last_written = false;
+        }
+        if !viz.step() {
+            break;
+        }
+        step += 1;
+    }
+    if !last_written && written < options.max_frames {
+        write(written, &viz.frame())?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// Writes the frames of a simulation to `path`
+///
+/// A `.gif` path gets an animation of every frame. A `.png` path gets one
+/// image per frame, numbered like `sand-0000.png`, `sand-0001.png` and so
+/// on next to it. Missing folders on the way are created. Returns the
+/// number of frames written.
+///
+/// # Errors
+///
+/// Fails if the path has another extension or the images can't be written
+pub fn export(viz: &mut dyn Visualize, path: &Path, options: &ExportOptions) -> AocResult<usize> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Unable to create {}", dir.display()))?;
+    }
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("gif") => {
+            let mut gif: Option<GifWriter> = None;
+            sample(viz, options, |_, frame| {
+                let gif = match &mut gif {
+                    Some(gif) => gif,
+                    None => gif.insert(GifWriter::create(path, frame, options)?),
+                };
+                gif.write(frame, options)
+            })
+        }
+        Some("png") => sample(viz, options, |index, frame| {
+            write_png(&sequence_path(path, index), frame, options)
+        }),
+        _ => anyhow::bail!(
+            "Unable to export to {}, expected a .gif or .png file",
+            path.display()
+        ),
+    }
+}
+
+#[test]
+fn test_export() {
+    /// A sand grain falling down a column
+    struct Falling(usize);
+
+    impl Visualize for Falling {
+        fn frame(&self) -> Frame {
+            let mut frame = Frame::new(2, 4);
+            frame.set(1, 3, '#');
+            frame.paint(0, self.0, 'o', Color::Yellow);
+            frame
+        }
+
+        fn step(&mut self) -> bool {
+            if self.0 == 3 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+    }
+
+    let dir = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = ExportOptions {
+        scale: 3,
+        frame_skip: 2,
+        ..ExportOptions::default()
+    };
+    let rgb = |cell| options.palette.rgb(cell).to_vec();
+    let yellow = rgb(('o', Some(Color::Yellow)));
+
+    // Steps 0 and 2 are kept by the skip, and step 3 because it is the last
+    let path = dir.join("sand.png");
+    assert_eq!(export(&mut Falling(0), &path, &options).unwrap(), 3);
+    assert!(!sequence_path(&path, 3).exists());
+    let decoder = png::Decoder::new(File::open(sequence_path(&path, 1)).unwrap());
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height), (6, 12));
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+    let pixel = |x: usize, y: usize| pixels[(y * 6 + x) * 3..][..3].to_vec();
+    assert_eq!(pixel(0, 6), yellow);
+    assert_eq!(pixel(2, 8), yellow);
+    assert_eq!(pixel(3, 6), rgb(('.', None)));
+    assert_eq!(pixel(5, 11), rgb(('#', None)));
+
+    let path = dir.join("sand.gif");
+    assert_eq!(export(&mut Falling(0), &path, &options).unwrap(), 3);
+    let mut decode = gif::DecodeOptions::new();
+    decode.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = decode.read_info(File::open(&path).unwrap()).unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (6, 12));
+    let mut frames = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!(frame.delay, 5);
+        frames.push(frame.buffer.to_vec());
+    }
+    assert_eq!(frames.len(), 3);
+    // The last frame has the grain on the bottom row, next to the rock
+    assert_eq!(frames[2][(9 * 6) * 4..][..3], yellow);
+    assert_eq!(frames[2][(9 * 6 + 3) * 4..][..3], rgb(('#', None)));
+
+    let limited = ExportOptions {
+        max_frames: 2,
+        ..ExportOptions::default()
+    };
+    assert_eq!(export(&mut Falling(0), &path, &limited).unwrap(), 2);
+    assert!(export(&mut Falling(0), &dir.join("sand.bmp"), &options).is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
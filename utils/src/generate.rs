@@ -0,0 +1,56 @@
+pub use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{RunOptions, Solution};
+
+/// Writes a random input for a day, about `size` items big
+///
+/// What an item is depends on the puzzle: an elf's snacks on day 1, a
+/// monkey's items on day 11, a sensor on day 15. The input is always
+/// one the day's own parser accepts.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+/// Random number generator that gives the same numbers for the same seed
+#[must_use]
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Checks that `solution` parses whatever `generate` writes
+///
+/// Tries a handful of seeds and sizes, parsing in strict mode so an
+/// input the parser stops early on fails too.
+///
+/// # Panics
+///
+/// Panics with the seed, size and input that didn't parse
+pub fn check<S: Solution>(solution: &S, generate: Generator) {
+    let options = RunOptions {
+        strict: true,
+        ..RunOptions::default()
+    };
+    for seed in 0..8 {
+        for size in [1, 2, 10, 50] {
+            let input = generate(&mut rng(seed), size);
+            let parsed = options.finish(&input, solution.parse(&input)).map(|_| ());
+            let parsed2 = match solution.shared() {
+                Some(_) => Ok(()),
+                None => options.finish(&input, solution.parse2(&input)).map(|_| ()),
+            };
+            if let Err(e) = parsed.and(parsed2) {
+                panic!(
+                    "seed {seed} and size {size} gave an input that doesn't parse: {e:#}\n{input}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let numbers = |seed| -> Vec<u32> {
+        let mut rng = rng(seed);
+        (0..8).map(|_| rng.gen()).collect()
+    };
+    assert_eq!(numbers(7), numbers(7));
+    assert_ne!(numbers(7), numbers(8));
+}
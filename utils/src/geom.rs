@@ -0,0 +1,341 @@
+use num_traits::{Num, Signed};
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Absolute difference that works for both signed and unsigned numbers
+fn abs_diff<T: Num + PartialOrd + Copy>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// A position in two dimensional space
+///
+/// Uses screen coordinates: `x` grows to the right and `y` grows
+/// downward, so `y` lines up with the rows of a [`Grid`](crate::grid::Grid).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A displacement between two [`Point2`]s
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Num + PartialOrd + Copy> Point2<T> {
+    /// Taxicab distance between two points
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Chessboard distance between two points
+    pub fn chebyshev(self, other: Self) -> T {
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
+impl<T: Signed + Copy> Point2<T> {
+    /// Moves one step towards `target`, diagonally if needed
+    #[must_use]
+    pub fn step_towards(self, target: Self) -> Self {
+        self + (target - self).signum()
+    }
+
+    /// The four orthogonal neighbors
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self + d.vector())
+    }
+
+    /// All eight surrounding neighbors
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(Direction::vector)
+            .chain(Diagonal::ALL.into_iter().map(Diagonal::vector))
+            .map(move |v| self + v)
+    }
+}
+
+impl Point2<usize> {
+    /// Adds a signed displacement, returning `None` on underflow or overflow
+    #[must_use]
+    pub fn checked_add_signed(self, v: Vec2<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(v.x)?,
+            self.y.checked_add_signed(v.y)?,
+        ))
+    }
+
+    /// Moves by `v`, returning `None` if the result leaves `0..width` by `0..height`
+    #[must_use]
+    pub fn checked_move(self, v: Vec2<isize>, width: usize, height: usize) -> Option<Self> {
+        self.checked_add_signed(v)
+            .filter(|p| p.x < width && p.y < height)
+    }
+
+    /// Converts to an ndarray `(row, column)` index
+    #[must_use]
+    pub const fn index(self) -> (usize, usize) {
+        (self.y, self.x)
+    }
+
+    /// Converts from an ndarray `(row, column)` index
+    #[must_use]
+    pub const fn from_index((row, col): (usize, usize)) -> Self {
+        Self::new(col, row)
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Num + PartialOrd + Copy> Vec2<T> {
+    /// Taxicab length of the vector
+    pub fn manhattan(self) -> T {
+        abs_diff(self.x, T::zero()) + abs_diff(self.y, T::zero())
+    }
+
+    /// Chessboard length of the vector
+    pub fn chebyshev(self) -> T {
+        Point2::new(self.x, self.y).chebyshev(Point2::new(T::zero(), T::zero()))
+    }
+}
+
+impl<T: Signed + Copy> Vec2<T> {
+    /// Reduces each component to `-1`, `0` or `1`
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// The four cardinal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction in clockwise order starting with [`Direction::Up`]
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Unit vector pointing in this direction
+    #[must_use]
+    pub fn vector<T: Signed>(self) -> Vec2<T> {
+        match self {
+            Self::Up => Vec2::new(T::zero(), -T::one()),
+            Self::Right => Vec2::new(T::one(), T::zero()),
+            Self::Down => Vec2::new(T::zero(), T::one()),
+            Self::Left => Vec2::new(-T::one(), T::zero()),
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+}
+
+/// The four diagonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diagonal {
+    UpRight,
+    DownRight,
+    DownLeft,
+    UpLeft,
+}
+
+impl Diagonal {
+    /// Every diagonal in clockwise order starting with [`Diagonal::UpRight`]
+    pub const ALL: [Self; 4] = [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    /// Vector pointing one step in this direction
+    #[must_use]
+    pub fn vector<T: Signed>(self) -> Vec2<T> {
+        let (vertical, horizontal) = self.split();
+        vertical.vector() + horizontal.vector()
+    }
+
+    /// The vertical and horizontal parts of the diagonal
+    #[must_use]
+    pub const fn split(self) -> (Direction, Direction) {
+        match self {
+            Self::UpRight => (Direction::Up, Direction::Right),
+            Self::DownRight => (Direction::Down, Direction::Right),
+            Self::DownLeft => (Direction::Down, Direction::Left),
+            Self::UpLeft => (Direction::Up, Direction::Left),
+        }
+    }
+}
+
+#[test]
+fn test_geom() {
+    let a = Point2::new(2_i64, 18);
+    let b = Point2::new(-2_i64, 15);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a - b, Vec2::new(4, 3));
+    assert_eq!(b.step_towards(a), Point2::new(-1, 16));
+    assert_eq!(b + Direction::Up.vector() * 2, Point2::new(-2, 13));
+    assert_eq!(Diagonal::DownLeft.vector::<i32>(), Vec2::new(-1, 1));
+    assert_eq!(Point2::new(0_i32, 0).neighbors8().count(), 8);
+
+    let p = Point2::new(3_usize, 1);
+    assert_eq!(p.index(), (1, 3));
+    assert_eq!(Point2::from_index((1, 3)), p);
+    assert_eq!(
+        p.checked_move(Direction::Up.vector(), 4, 4),
+        Some(Point2::new(3, 0))
+    );
+    assert_eq!(p.checked_move(Direction::Right.vector(), 4, 4), None);
+    assert_eq!(
+        Point2::new(0_usize, 0).checked_add_signed(Vec2::new(-1, 0)),
+        None
+    );
+    assert_eq!(Point2::new(1_u32, 5).manhattan(Point2::new(4, 2)), 6);
+}
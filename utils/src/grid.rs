@@ -0,0 +1,327 @@
+use crate::geom::Point2;
+use ndarray::{s, Array2, ArrayView1, Axis};
+use std::{
+    fmt::{self, Display},
+    ops::{Deref, DerefMut, Index, IndexMut},
+};
+
+/// A position in a grid as `(row, column)`
+pub type Pos = (usize, usize);
+
+/// Offsets for the four orthogonal neighbors as `(row, column)` deltas
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets for all eight surrounding neighbors as `(row, column)` deltas
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Two dimensional grid of cells indexed by `(row, column)`
+///
+/// Dereferences to the underlying [`Array2`] so every ndarray
+/// operation is still available.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T>(Array2<T>);
+
+impl<T> Grid<T> {
+    /// Builds a grid from a character map, mapping every cell with `f`
+    ///
+    /// Lines are split on `\n` (a trailing `\r` is ignored) and a
+    /// trailing newline at the end of the input is allowed.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is empty, the lines are not all the same
+    /// length, or `f` returns an error for a cell.
+    pub fn try_from_text<F, E>(input: &str, mut f: F) -> anyhow::Result<Self>
+    where
+        F: FnMut(Pos, char) -> Result<T, E>,
+        E: Into<anyhow::Error>,
+    {
+        let lines: Vec<&str> = input
+            .trim_end_matches(['\r', '\n'])
+            .split('\n')
+            .map(|l| l.strip_suffix('\r').unwrap_or(l))
+            .collect();
+        let width = lines[0].chars().count();
+        if width == 0 {
+            anyhow::bail!("grid is empty");
+        }
+        let mut cells = Vec::with_capacity(lines.len() * width);
+        for (row, line) in lines.iter().enumerate() {
+            let before = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                cells.push(f((row, col), c).map_err(Into::into)?);
+            }
+            let len = cells.len() - before;
+            if len != width {
+                anyhow::bail!("row {row} has {len} cells, expected {width}");
+            }
+        }
+        Ok(Self(Array2::from_shape_vec((lines.len(), width), cells)?))
+    }
+
+    /// Builds a grid from a character map, mapping every cell with `f`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is empty or the lines are not all the same length.
+    pub fn from_text<F>(input: &str, mut f: F) -> anyhow::Result<Self>
+    where
+        F: FnMut(char) -> T,
+    {
+        Self::try_from_text(input, |_, c| Ok::<T, anyhow::Error>(f(c)))
+    }
+
+    /// Creates a grid with every cell set to `value`
+    #[must_use]
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self(Array2::from_elem((rows, cols), value))
+    }
+
+    /// Number of rows in the grid
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.0.nrows()
+    }
+
+    /// Number of columns in the grid
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.0.ncols()
+    }
+
+    /// Checks if the position is inside the grid
+    #[must_use]
+    pub fn in_bounds(&self, (row, col): Pos) -> bool {
+        row < self.height() && col < self.width()
+    }
+
+    /// Moves `pos` by `delta`, returning `None` if it leaves the grid
+    #[must_use]
+    pub fn offset(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// Positions of the orthogonal neighbors that are inside the grid
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS_4
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Positions of all surrounding neighbors that are inside the grid
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS_8
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Walks from `pos` in the direction of `delta` until the edge of the grid
+    ///
+    /// The starting position itself is not included.
+    pub fn ray(&self, pos: Pos, delta: (isize, isize)) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        std::iter::successors(self.offset(pos, delta), move |&p| self.offset(p, delta))
+            .map(|p| (p, &self.0[p]))
+    }
+
+    /// View of a single row
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds
+    #[must_use]
+    pub fn row(&self, row: usize) -> ArrayView1<'_, T> {
+        self.0.row(row)
+    }
+
+    /// View of a single column
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` is out of bounds
+    #[must_use]
+    pub fn column(&self, col: usize) -> ArrayView1<'_, T> {
+        self.0.column(col)
+    }
+
+    /// Iterates over every cell with its position
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.0.indexed_iter()
+    }
+
+    /// Finds the position of the first cell matching `pred` in row-major order
+    pub fn position<P>(&self, mut pred: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells().find(|(_, c)| pred(c)).map(|(p, _)| p)
+    }
+
+    /// Creates a new grid by mapping every cell
+    #[must_use]
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid(self.0.map(f))
+    }
+
+    /// Swaps rows and columns
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self(self.0.t().to_owned())
+    }
+
+    /// Rotates the grid a quarter turn clockwise
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut rotated = self.0.t().to_owned();
+        rotated.invert_axis(Axis(1));
+        Self(rotated)
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut rotated = self.0.t().to_owned();
+        rotated.invert_axis(Axis(0));
+        Self(rotated)
+    }
+
+    /// Returns a copy of the rectangle from `top_left` up to, but not including, `bottom_right`
+    #[must_use]
+    pub fn crop(&self, (r1, c1): Pos, (r2, c2): Pos) -> Self
+    where
+        T: Clone,
+    {
+        Self(self.0.slice(s![r1..r2, c1..c2]).to_owned())
+    }
+
+    /// Renders the grid as text, turning every cell into a character with `f`
+    pub fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        self.0
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Unwraps the underlying array
+    #[must_use]
+    pub fn into_inner(self) -> Array2<T> {
+        self.0
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(arr: Array2<T>) -> Self {
+        Self(arr)
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = Array2<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Grid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self.0[pos]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        &mut self.0[pos]
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2<usize>) -> &Self::Output {
+        &self.0[pos.index()]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point2<usize>) -> &mut Self::Output {
+        &mut self.0[pos.index()]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.0.rows().into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid() {
+    let grid = Grid::from_text("123\n456\n", |c| c.to_digit(10).unwrap()).unwrap();
+    assert_eq!((grid.height(), grid.width()), (2, 3));
+    assert_eq!(grid[(1, 2)], 6);
+    assert_eq!(grid[Point2::new(2, 1)], 6);
+    assert_eq!(grid.to_string(), "123\n456");
+    assert_eq!(
+        grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+        [(0, 1), (1, 0)]
+    );
+    assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    assert_eq!(
+        grid.ray((0, 0), (0, 1))
+            .map(|(_, &c)| c)
+            .collect::<Vec<_>>(),
+        [2, 3]
+    );
+    assert_eq!(grid.column(1).to_vec(), [2, 5]);
+    assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+    assert_eq!(grid.rotate_cw().to_string(), "41\n52\n63");
+    assert_eq!(grid.rotate_ccw().to_string(), "36\n25\n14");
+    assert_eq!(grid.render(|&c| if c > 3 { '#' } else { '.' }), "...\n###");
+    assert!(Grid::from_text("12\n3", |c| c).is_err());
+}
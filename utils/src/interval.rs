@@ -0,0 +1,393 @@
+use num_traits::PrimInt;
+use std::{
+    fmt::{self, Display},
+    ops::RangeInclusive,
+};
+
+/// Closed interval of integers `start..=end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    /// Creates the interval `start..=end`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `end`, which converting a range
+    /// with `try_from` reports as an [`EmptyRange`] instead
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "interval start is greater than its end");
+        Self { start, end }
+    }
+
+    /// Number of integers in the interval
+    ///
+    /// # Panics
+    ///
+    /// Overflows when the interval covers every value of `T`, like
+    /// `0..=u8::MAX`, which panics in debug builds
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> T {
+        self.end - self.start + T::one()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Checks if `other` lies completely inside this interval
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Checks if the intervals share at least one value
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Checks if the intervals overlap or sit right next to each other
+    pub fn touches(&self, other: &Self) -> bool {
+        self.overlaps(other)
+            || after(self.end) == Some(other.start)
+            || after(other.end) == Some(self.start)
+    }
+
+    /// The values both intervals have in common
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other)
+            .then(|| Self::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+}
+
+fn after<T: PrimInt>(value: T) -> Option<T> {
+    value.checked_add(&T::one())
+}
+
+fn before<T: PrimInt>(value: T) -> Option<T> {
+    value.checked_sub(&T::one())
+}
+
+/// A range with no values, like `5..=3`, turned into an [`Interval`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyRange;
+
+impl Display for EmptyRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "range start is greater than its end")
+    }
+}
+
+impl std::error::Error for EmptyRange {}
+
+impl<T: PrimInt> TryFrom<RangeInclusive<T>> for Interval<T> {
+    type Error = EmptyRange;
+
+    fn try_from(range: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        if start <= end {
+            Ok(Self { start, end })
+        } else {
+            Err(EmptyRange)
+        }
+    }
+}
+
+impl<T> From<Interval<T>> for RangeInclusive<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..=interval.end
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as sorted, disjoint intervals
+///
+/// Intervals that overlap or sit next to each other are merged on
+/// insertion, so `1..=3` and `4..=5` are stored as `1..=5`. Empty ranges
+/// like `5..=3` have no values, so they change nothing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value of `interval` to the set
+    pub fn insert(&mut self, interval: impl TryInto<Interval<T>>) {
+        let Ok(mut merged) = interval.try_into() else {
+            return;
+        };
+        let first = self
+            .intervals
+            .partition_point(|i| i.end < merged.start && !i.touches(&merged));
+        let last = first
+            + self.intervals[first..]
+                .iter()
+                .take_while(|i| i.touches(&merged))
+                .count();
+        for i in &self.intervals[first..last] {
+            merged = Interval::new(merged.start.min(i.start), merged.end.max(i.end));
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Removes every value of `interval` from the set
+    pub fn remove(&mut self, interval: impl TryInto<Interval<T>>) {
+        let Ok(removed) = interval.try_into() else {
+            return;
+        };
+        let mut kept = Vec::with_capacity(self.intervals.len() + 1);
+        for i in &self.intervals {
+            if !i.overlaps(&removed) {
+                kept.push(*i);
+                continue;
+            }
+            if i.start < removed.start {
+                kept.push(Interval::new(
+                    i.start,
+                    before(removed.start).unwrap_or(i.start),
+                ));
+            }
+            if removed.end < i.end {
+                kept.push(Interval::new(after(removed.end).unwrap_or(i.end), i.end));
+            }
+        }
+        self.intervals = kept;
+    }
+
+    /// Values found in either set
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    /// Values found in both sets
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        let (mut left, mut right) = (self.iter().peekable(), other.iter().peekable());
+        while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+            if let Some(i) = l.intersection(r) {
+                intersection.intervals.push(i);
+            }
+            if l.end < r.end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        intersection
+    }
+
+    /// Values found in this set but not in `other`
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Self::new();
+        let mut first = 0;
+        for i in self {
+            // Removed intervals ending before this one can't touch the next ones either
+            first += other.intervals[first..]
+                .iter()
+                .take_while(|r| r.end < i.start)
+                .count();
+            let mut start = Some(i.start);
+            for r in other.intervals[first..]
+                .iter()
+                .take_while(|r| r.start <= i.end)
+            {
+                let Some(kept) = start else { break };
+                if kept < r.start {
+                    difference
+                        .intervals
+                        .push(Interval::new(kept, r.start - T::one()));
+                }
+                start = after(r.end).filter(|&next| next <= i.end);
+            }
+            if let Some(kept) = start {
+                difference.intervals.push(Interval::new(kept, i.end));
+            }
+        }
+        difference
+    }
+
+    /// Restricts the set to values inside `bounds`
+    #[must_use]
+    pub fn clip(&self, bounds: impl TryInto<Interval<T>>) -> Self {
+        self.intersection(&Self::from_iter([bounds]))
+    }
+
+    /// Total number of values in the set
+    ///
+    /// # Panics
+    ///
+    /// Overflows when the set covers every value of `T`, like
+    /// `0..=u8::MAX`, which panics in debug builds
+    #[must_use]
+    pub fn total_len(&self) -> T {
+        self.iter().fold(T::zero(), |acc, i| acc + i.len())
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Checks if every value of `interval` is in the set, which an empty
+    /// range always is
+    pub fn contains_interval(&self, interval: impl TryInto<Interval<T>>) -> bool {
+        interval.try_into().map_or(true, |interval| {
+            self.find(interval.start)
+                .is_some_and(|i| i.contains_interval(&interval))
+        })
+    }
+
+    /// The stored interval that contains `value`
+    pub fn find(&self, value: T) -> Option<&Interval<T>> {
+        let index = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(index).filter(|i| i.contains(value))
+    }
+
+    /// The smallest interval covering every value in the set
+    #[must_use]
+    pub fn span(&self) -> Option<Interval<T>> {
+        Some(Interval::new(
+            self.intervals.first()?.start,
+            self.intervals.last()?.end,
+        ))
+    }
+
+    /// Missing values between the stored intervals
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|w| Interval::new(w[0].end + T::one(), w[1].start - T::one()))
+    }
+
+    /// The stored intervals in ascending order
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: PrimInt, I: TryInto<Interval<T>>> Extend<I> for IntervalSet<T> {
+    fn extend<It: IntoIterator<Item = I>>(&mut self, iter: It) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: PrimInt, I: TryInto<Interval<T>>> FromIterator<I> for IntervalSet<T> {
+    fn from_iter<It: IntoIterator<Item = I>>(iter: It) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[test]
+fn test_interval() {
+    let a = Interval::new(2, 8);
+    assert!(a.contains_interval(&Interval::new(3, 7)));
+    assert!(a.overlaps(&Interval::new(8, 9)));
+    assert!(!a.overlaps(&Interval::new(9, 9)));
+    assert!(a.touches(&Interval::new(9, 9)));
+    assert_eq!(
+        a.intersection(&Interval::new(6, 10)),
+        Some(Interval::new(6, 8))
+    );
+    assert_eq!(a.len(), 7);
+}
+
+#[test]
+fn test_interval_set() {
+    let mut set: IntervalSet<i64> = [-2..=2, 12..=12, 2..=14, 16..=24, 14..=18]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        [Interval::new(-2, 24)]
+    );
+    assert_eq!(set.total_len(), 27);
+
+    set.remove(5..=7);
+    set.remove(10..=10);
+    assert_eq!(
+        set.gaps().collect::<Vec<_>>(),
+        [Interval::new(5, 7), Interval::new(10, 10)]
+    );
+    assert!(set.contains(4) && !set.contains(6));
+    assert!(set.contains_interval(11..=24) && !set.contains_interval(9..=11));
+
+    let other: IntervalSet<i64> = [0..=5, 9..=11].into_iter().collect();
+    assert_eq!(set.intersection(&other).total_len(), 7);
+    assert_eq!(set.union(&other).gaps().count(), 1);
+    assert_eq!(
+        set.difference(&other).iter().copied().collect::<Vec<_>>(),
+        [
+            Interval::new(-2, -1),
+            Interval::new(8, 8),
+            Interval::new(12, 24)
+        ]
+    );
+    assert_eq!(set.clip(0..=20).span(), Some(Interval::new(0, 20)));
+
+    // Built with `new` since clippy rejects reversed range literals
+    let reversed = |start, end| RangeInclusive::new(start, end);
+    let mut set: IntervalSet<u32> = [reversed(5, 3), 1..=2].into_iter().collect();
+    set.insert(reversed(9, 8));
+    set.remove(reversed(2, 1));
+    assert_eq!(set.span(), Some(Interval::new(1, 2)));
+    assert!(set.contains_interval(reversed(4, 3)) && set.clip(reversed(7, 0)).is_empty());
+    assert_eq!(Interval::try_from(reversed(5, 3)), Err(EmptyRange));
+
+    let mut edges: IntervalSet<u8> = [0..=255].into_iter().collect();
+    edges.remove(0..=0);
+    edges.remove(255..=255);
+    assert_eq!(edges.span(), Some(Interval::new(1, 254)));
+    let holes: IntervalSet<u8> = [1..=1, 3..=4, 254..=255].into_iter().collect();
+    let wide: IntervalSet<u8> = [0..=2, 4..=200, 250..=255].into_iter().collect();
+    assert_eq!(
+        wide.difference(&holes).iter().copied().collect::<Vec<_>>(),
+        [
+            Interval::new(0, 0),
+            Interval::new(2, 2),
+            Interval::new(5, 200),
+            Interval::new(250, 253)
+        ]
+    );
+}
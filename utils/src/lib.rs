@@ -1,32 +1,336 @@
+pub mod answer;
+pub mod answers;
+pub mod cycle;
+pub mod error;
+pub mod examples;
+pub mod export;
+#[cfg(feature = "gen")]
+pub mod generate;
+pub mod geom;
+pub mod grid;
+pub mod interval;
+pub mod memory;
+pub mod ocr;
+pub mod params;
+pub mod parsers;
+pub mod report;
+pub mod search;
+pub mod solution;
+pub mod svg;
+pub mod timing;
+pub mod viz;
+
+pub use answer::{Answer, AocAnswer};
+use anyhow::Context;
 pub use anyhow::Result as AocResult;
 pub use clap::Parser;
-use clap::{builder::PossibleValue, ValueEnum};
-use std::path::PathBuf;
+use clap::{builder::PossibleValue, ArgGroup, Args, ValueEnum};
+pub use params::{Param, Params};
+pub use solution::{FnSolution, Solution, SplitFnSolution};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
 
-pub type ParseResult<'a, T> = nom::IResult<&'a str, T>;
+pub type ParseResult<'a, T> = nom::IResult<&'a str, T, nom::error::VerboseError<&'a str>>;
 
 #[derive(Debug, Parser)]
 #[command(author, about, long_about = None)]
+#[command(group(
+    ArgGroup::new("source")
+        .required(true)
+        .args(["input_file", "input_str", "example"]),
+))]
 pub struct Cli {
-    /// Input file
-    input_file: PathBuf,
+    /// Input file, or `-` to read from stdin
+    input_file: Option<PathBuf>,
 
-    /// Part of the task to do
+    /// Use the given text as input
+    #[arg(long, value_name = "INPUT")]
+    input_str: Option<String>,
+
+    /// Use `examples/<EXAMPLE>.txt` from the day's folder as input
+    #[arg(short, long)]
+    example: Option<String>,
+
+    #[command(flatten)]
+    pub run: RunOptions,
+
+    /// Compare the answers with the day's `answers.toml`, or the example's
+    /// sidecar, and fail on a mismatch
+    #[arg(long)]
+    check: bool,
+
+    /// How to print the results
     #[arg(short, long, value_enum, default_value_t)]
-    part: SolutionPart,
+    format: OutputFormat,
+
+    /// Print details of the answers, like the picture behind a decoded text
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Watch the simulation of part 1, or of `--part 2`, in the terminal
+    #[arg(long, conflicts_with_all = ["check", "format"])]
+    visualize: bool,
+
+    /// Write the frames of the simulation to a `.gif`, or to numbered `.png` files
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["check", "format", "visualize"])]
+    export: Option<PathBuf>,
+
+    /// Draw the answer of part 1, or of `--part 2`, to an SVG file
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["check", "format", "visualize", "export"])]
+    svg: Option<PathBuf>,
+
+    #[command(flatten, next_help_heading = "Export")]
+    export_options: export::ExportOptions,
+
+    /// Folder of the day's package, used to find the examples
+    #[arg(skip)]
+    day_dir: Option<PathBuf>,
 }
 
 impl Cli {
-    /// Reads input file and returns its content
+    /// Sets the folder examples are looked up in
+    #[must_use]
+    pub fn with_day_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.day_dir = Some(dir.into());
+        self
+    }
+
+    /// Folder of the day's package, the current folder if it isn't known
+    fn day_dir(&self) -> &Path {
+        self.day_dir.as_deref().unwrap_or_else(|| Path::new("."))
+    }
+
+    /// Sidecar of the example picked with `--example`, if it has one
+    fn example_sidecar(&self) -> AocResult<Option<answers::Answers>> {
+        let Some(example) = &self.example else {
+            return Ok(None);
+        };
+        let path = self
+            .day_dir()
+            .join("examples")
+            .join(format!("{example}.toml"));
+        if path.exists() {
+            answers::Answers::load_file(&path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the puzzle input from whichever source was given
+    ///
+    /// The input comes from `--input-str`, an example picked with
+    /// `--example`, stdin when the input file is `-`, or the input file.
     ///
     /// # Errors
     ///
-    /// Check errors for [`std::fs::read_to_string`]
+    /// Fails if the file or stdin can't be read
     pub fn input(&self) -> AocResult<String> {
-        Ok(std::fs::read_to_string(&self.input_file)?)
+        if let Some(input) = &self.input_str {
+            return Ok(input.clone());
+        }
+        if let Some(example) = &self.example {
+            let path = self
+                .day_dir()
+                .join("examples")
+                .join(format!("{example}.txt"));
+            return std::fs::read_to_string(&path)
+                .with_context(|| format!("Unable to read example {}", path.display()));
+        }
+        match &self.input_file {
+            Some(path) if path == Path::new("-") => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Some(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Unable to read {}", path.display())),
+            None => anyhow::bail!("No input given"),
+        }
     }
 
-    /// Determines if the part should run based on cli flags
+    /// Runs a solution on the input and prints the report
+    ///
+    /// With `--visualize` the simulation is played in the terminal instead,
+    /// with `--export` its frames are written to images, and with `--svg`
+    /// the answer is drawn.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input can't be read, with the first failure of the
+    /// solution, or if `--check` finds answers that don't match
+    pub fn run<S: Solution + ?Sized>(&self, solution: &S) -> AocResult<()> {
+        let input = self.input()?;
+        let sidecar = self.example_sidecar()?;
+        let mut options = self.run.clone();
+        if let Some(sidecar) = &sidecar {
+            options.params.splice(0..0, sidecar.params());
+        }
+        if self.visualize {
+            let mut viz = solution::visualize(solution, &input, &options)?;
+            return viz::play(viz.as_mut());
+        }
+        if let Some(path) = &self.export {
+            let mut viz = solution::visualize(solution, &input, &options)?;
+            let frames = export::export(viz.as_mut(), path, &self.export_options)?;
+            println!("Wrote {frames} frames to {}", path.display());
+            return Ok(());
+        }
+        if let Some(path) = &self.svg {
+            solution::svg(solution, &input, &options)?.save(path)?;
+            println!("Wrote {}", path.display());
+            return Ok(());
+        }
+        let report = solution::run(solution, &input, &options);
+        let mismatches = match (self.check, sidecar) {
+            (false, _) => Vec::new(),
+            (true, Some(sidecar)) => sidecar.check(&report),
+            (true, None) => answers::Answers::load(self.day_dir())?.check(&report),
+        };
+        self.output(report)?;
+        for mismatch in &mismatches {
+            eprintln!("{mismatch}");
+        }
+        anyhow::ensure!(
+            mismatches.is_empty(),
+            "{} answers do not match",
+            mismatches.len()
+        );
+        Ok(())
+    }
+
+    /// Prints the report in the format picked with `--format`
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failure recorded in the report, so the process
+    /// exits with an error status
+    pub fn output(&self, mut report: report::Report) -> AocResult<()> {
+        match self.format {
+            OutputFormat::Text => {
+                for part in &report.parts {
+                    match &part.answer {
+                        Some(answer @ Answer::Picture(_)) => {
+                            println!("Part {}:\n{answer}", part.part);
+                        }
+                        Some(answer) => println!("Part {}: {answer}", part.part),
+                        None => {}
+                    }
+                    if let Some(details) = part.details.as_ref().filter(|_| self.verbose) {
+                        println!("{details}");
+                    }
+                }
+                if self.run.time {
+                    println!("Timings:");
+                    for report::PhaseTiming { phase, timing, .. } in &report.timings {
+                        println!("  {phase:<8} {timing}");
+                    }
+                }
+                if report.timings.iter().any(|t| t.memory.is_some()) {
+                    println!("Memory:");
+                    for report::PhaseTiming { phase, memory, .. } in &report.timings {
+                        if let Some(memory) = memory {
+                            println!("  {phase:<8} {memory}");
+                        }
+                    }
+                }
+            }
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            }
+        }
+        report.take_error().map_or(Ok(()), Err)
+    }
+}
+
+/// Options deciding which parts run and how they are measured
+#[derive(Debug, Clone, Args)]
+pub struct RunOptions {
+    /// Part of the task to do
+    #[arg(short, long, value_enum, default_value_t)]
+    pub part: SolutionPart,
+
+    /// Fail instead of warning when the parser leaves input unconsumed
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Print how long parsing and each part took
+    #[arg(short, long)]
+    pub time: bool,
+
+    /// Print what parsing and each part allocated, needs the `mem` feature
+    #[arg(long)]
+    pub mem: bool,
+
+    /// Run every phase this many times and report min, median and max
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub repeat: u32,
+
+    /// Override a parameter of the solution, like `--param row=10`
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_param)]
+    pub params: Vec<(String, String)>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            part: SolutionPart::Both,
+            strict: false,
+            time: false,
+            mem: false,
+            repeat: 1,
+            params: Vec::new(),
+        }
+    }
+}
+
+impl RunOptions {
+    /// Finishes a parser run on the input
+    ///
+    /// Input left over after parsing, other than trailing whitespace,
+    /// is reported as a warning, or as an error in strict mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`error::ParseError`] if the parser failed, or if it
+    /// left input unconsumed in strict mode
+    pub fn finish<'a, T>(&self, input: &'a str, result: ParseResult<'a, T>) -> AocResult<T> {
+        let (remaining, parsed) = error::finish(input, result)?;
+        if let Err(e) = error::check_consumed(input, remaining) {
+            if self.strict {
+                return Err(e.into());
+            }
+            eprintln!("Warning: {e}");
+        }
+        Ok(parsed)
+    }
+
+    /// Runs one phase of the solution, measuring it as often as `--repeat` asks
+    ///
+    /// With `--mem` the allocations are counted too. The counts are per
+    /// run, while the heap peak is the highest of any run.
+    pub fn time<T, F>(&self, f: F) -> (T, report::Measured)
+    where
+        F: FnMut() -> T,
+    {
+        let runs = self.repeat as usize;
+        if !self.mem {
+            let (result, timing) = timing::time(runs, f);
+            return (result, timing.into());
+        }
+        let ((result, timing), memory) = memory::measure(|| timing::time(runs, f));
+        let runs = timing.runs().max(1);
+        let memory = memory::Memory {
+            allocations: memory.allocations / runs,
+            bytes: memory.bytes / runs,
+            ..memory
+        };
+        let memory = Some(memory);
+        (result, report::Measured { timing, memory })
+    }
+
+    /// Determines if the part should run based on `--part`
     #[must_use]
     pub fn should_run(&self, part: SolutionPart) -> bool {
         if self.part == SolutionPart::Both {
@@ -37,6 +341,15 @@ impl Cli {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Answers for people to read
+    #[default]
+    Text,
+    /// A JSON object with the answers, timings and errors
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum SolutionPart {
     PartOne,
@@ -72,54 +385,43 @@ impl ValueEnum for SolutionPart {
     }
 }
 
+/// Writes a `main` that runs a [`Solution`] with the options from [`Cli`]
+///
+/// Takes either a value implementing [`Solution`], or the `parse`,
+/// `part1` and `part2` functions, with a `parse2` after `parse` when
+/// part 2 reads the input differently.
 #[macro_export]
 macro_rules! aoc_main {
     ($parse:ident, $part1:ident, $part2:ident) => {
-        fn main() -> AocResult<()> {
-            let cli = Cli::parse();
-            let input = cli.input()?;
-            let parsed = $parse(&input);
-            let (_, parsed) = aoc_main!(@finalize, parsed);
-            if cli.should_run(SolutionPart::PartOne) {
-                let part1 = $part1(&parsed)?;
-                println!("Part 1: {:#?}", part1);
-            }
-            if cli.should_run(SolutionPart::PartTwo) {
-                let part2 = $part2(&parsed)?;
-                println!("Part 2: {:#?}", part2);
-            }
-            Ok(())
-        }
+        $crate::aoc_main!($crate::solution::FnSolution::new(
+            $crate::report::day_number(env!("CARGO_PKG_NAME")),
+            $parse,
+            $part1,
+            $part2,
+        ));
     };
 
     ($parse1:ident, $parse2:ident, $part1:ident, $part2:ident) => {
-        fn main() -> AocResult<()> {
-            let cli = Cli::parse();
-            let input = cli.input()?;
-            if cli.should_run(SolutionPart::PartOne) {
-                let parsed = $parse1(&input);
-                let (_, parsed) = aoc_main!(@finalize, parsed);
-                let part1 = $part1(&parsed)?;
-                println!("Part 1: {:#?}", part1);
-            }
-            if cli.should_run(SolutionPart::PartTwo) {
-                let parsed = $parse2(&input);
-                let (_, parsed) = aoc_main!(@finalize, parsed);
-                let part2 = $part2(&parsed)?;
-                println!("Part 2: {:#?}", part2);
-            }
-            Ok(())
-        }
+        $crate::aoc_main!($crate::solution::SplitFnSolution::new(
+            $crate::report::day_number(env!("CARGO_PKG_NAME")),
+            $parse1,
+            $parse2,
+            $part1,
+            $part2,
+        ));
     };
 
-    (@finalize, $parsed:expr) => {
-        nom::Finish::finish($parsed).map_err(|nom::error::Error { input, code }| {
-            nom::error::Error {
-                input: input.to_string(),
-                code,
-            }
-        })?
-    }
+    ($solution:expr) => {
+        #[cfg(feature = "mem")]
+        #[global_allocator]
+        static ALLOCATOR: $crate::memory::CountingAlloc = $crate::memory::CountingAlloc;
+
+        fn main() -> $crate::AocResult<()> {
+            let cli =
+                <$crate::Cli as $crate::Parser>::parse().with_day_dir(env!("CARGO_MANIFEST_DIR"));
+            cli.run(&$solution)
+        }
+    };
 }
 
 #[macro_export]
@@ -127,7 +429,10 @@ macro_rules! assert_part {
     ($parse:ident, $part:ident, $input:expr, $equals:expr) => {{
         let input = $parse($input).unwrap().1;
         let part = $part(&input).unwrap();
-        assert_eq!(format!("{:?}", part), format!("{:?}", $equals))
+        assert_eq!(
+            $crate::AocAnswer::answer(&part),
+            $crate::AocAnswer::answer(&$equals)
+        )
     }};
 }
 
@@ -0,0 +1,223 @@
+use serde::Serialize;
+use std::{
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap use of one phase, as seen by [`CountingAlloc`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Memory {
+    /// Number of allocations, reallocations included
+    pub allocations: usize,
+    /// Bytes asked for over all of the allocations
+    pub bytes: usize,
+    /// Most heap bytes held at once on top of what was held before the
+    /// phase, which leaves out the stack, the code and the allocator's own
+    /// overhead
+    pub peak_heap_bytes: usize,
+    /// Highest resident set size of the whole process by the end of the
+    /// phase, earlier phases included, when the system tells it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_rss_bytes: Option<usize>,
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak heap",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak_heap_bytes)
+        )?;
+        if let Some(rss) = self.peak_rss_bytes {
+            write!(f, ", {} peak RSS", Bytes(rss))?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a number of bytes with the binary unit that fits its size
+#[derive(Debug, Clone, Copy)]
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{}B", self.0);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let mut value = self.0 as f64;
+        let mut unit = "B";
+        for next in UNITS {
+            if value < 1024.0 {
+                break;
+            }
+            value /= 1024.0;
+            unit = next;
+        }
+        write!(f, "{value:.2}{unit}")
+    }
+}
+
+/// Tells if [`CountingAlloc`] is the global allocator of the program
+#[must_use]
+pub fn is_counting() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// Highest resident set size of the process so far, the `VmHWM` line of
+/// `/proc/self/status`
+///
+/// `None` where there's no such file, like outside of Linux.
+#[must_use]
+pub fn peak_rss() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+/// Runs `f`, counting what it allocates
+///
+/// The counts are all zero unless [`CountingAlloc`] is the global
+/// allocator. Allocations of other threads are counted too, so
+/// measurements running at the same time on several threads see each
+/// other's allocations. A measurement nested in another one doesn't hide
+/// its heap peak from the outer one.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Memory) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    let outer_peak = PEAK.swap(live, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.fetch_max(outer_peak, Ordering::Relaxed);
+    let memory = Memory {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_heap_bytes: peak.saturating_sub(live),
+        peak_rss_bytes: peak_rss(),
+    };
+    (result, memory)
+}
+
+#[cfg_attr(not(feature = "mem"), allow(dead_code))]
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+#[cfg_attr(not(feature = "mem"), allow(dead_code))]
+fn record_dealloc(size: usize) {
+    LIVE.fetch_sub(size, Ordering::Relaxed);
+}
+
+/// The system allocator, counting allocations for `--mem`
+///
+/// Installed by [`aoc_main!`](crate::aoc_main) when the day is built
+/// with its `mem` feature.
+#[cfg(feature = "mem")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CountingAlloc;
+
+#[cfg(feature = "mem")]
+unsafe impl std::alloc::GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        COUNTING.store(true, Ordering::Relaxed);
+        let ptr = std::alloc::System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: std::alloc::Layout) -> *mut u8 {
+        COUNTING.store(true, Ordering::Relaxed);
+        let ptr = std::alloc::System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        std::alloc::System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize) -> *mut u8 {
+        let new = std::alloc::System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new
+    }
+}
+
+#[test]
+fn test_memory() {
+    let (_, memory) = measure(|| {
+        record_alloc(100);
+        record_alloc(50);
+        record_dealloc(100);
+        record_alloc(20);
+        record_dealloc(50);
+        record_dealloc(20);
+    });
+    // Only this thread records here, other tests don't touch the counters
+    assert_eq!(
+        memory,
+        Memory {
+            allocations: 3,
+            bytes: 170,
+            peak_heap_bytes: 150,
+            peak_rss_bytes: memory.peak_rss_bytes,
+        }
+    );
+    assert_eq!(memory.peak_rss_bytes.is_some(), cfg!(target_os = "linux"));
+    assert_eq!(
+        Memory {
+            peak_rss_bytes: None,
+            ..memory
+        }
+        .to_string(),
+        "3 allocations, 170B allocated, 150B peak heap"
+    );
+    assert_eq!(
+        Memory {
+            peak_rss_bytes: Some(3 * 1024 * 1024),
+            ..memory
+        }
+        .to_string(),
+        "3 allocations, 170B allocated, 150B peak heap, 3.00MiB peak RSS"
+    );
+
+    let ((), outer) = measure(|| {
+        record_alloc(100);
+        record_dealloc(100);
+        let ((), inner) = measure(|| {
+            record_alloc(10);
+            record_dealloc(10);
+        });
+        assert_eq!(inner.peak_heap_bytes, 10);
+    });
+    assert_eq!(outer.peak_heap_bytes, 100);
+    assert_eq!(Bytes(1536).to_string(), "1.50KiB");
+    assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.00MiB");
+}
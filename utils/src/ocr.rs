@@ -0,0 +1,129 @@
+use crate::grid::Grid;
+
+/// A letter of one of the block fonts, one string per row
+type Glyph = (char, &'static [&'static str]);
+
+/// The 4 wide and 6 tall font most CRT and display puzzles draw with
+#[rustfmt::skip]
+const FONT_6: &[Glyph] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The 6 wide and 10 tall font of the star message puzzles
+#[rustfmt::skip]
+const FONT_10: &[Glyph] = &[
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Reads the letters drawn in `lines`, where `#` is a lit pixel
+///
+/// Any other character is dark, so both `.` and space work as the
+/// background. Returns `None` if the picture isn't 6 or 10 lit rows
+/// tall or has a shape that isn't a known letter.
+pub fn recognize<S: AsRef<str>>(lines: &[S]) -> Option<String> {
+    let pixels: Vec<Vec<bool>> = lines
+        .iter()
+        .map(|line| line.as_ref().chars().map(|c| c == '#').collect())
+        .collect();
+    recognize_pixels(&pixels)
+}
+
+/// Reads the letters drawn in a grid of lit pixels
+///
+/// See [`recognize`].
+#[must_use]
+pub fn recognize_grid(grid: &Grid<bool>) -> Option<String> {
+    let pixels: Vec<Vec<bool>> = grid.rows().into_iter().map(|r| r.to_vec()).collect();
+    recognize_pixels(&pixels)
+}
+
+fn recognize_pixels(pixels: &[Vec<bool>]) -> Option<String> {
+    let lit_row = |row: &Vec<bool>| row.contains(&true);
+    let first = pixels.iter().position(lit_row)?;
+    let last = pixels.iter().rposition(lit_row)?;
+    let rows = &pixels[first..=last];
+    let font = match rows.len() {
+        6 => FONT_6,
+        10 => FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let lit = |row: &Vec<bool>, col: usize| row.get(col).copied().unwrap_or_default();
+    let lit_column = |col: usize| rows.iter().any(|row| lit(row, col));
+    let mut letters = String::new();
+    let mut col = 0;
+    while col < width {
+        if !lit_column(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < width && lit_column(col) {
+            col += 1;
+        }
+        let glyph: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                (start..col)
+                    .map(|c| if lit(row, c) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let (letter, _) = font.iter().find(|(_, shape)| *shape == glyph.as_slice())?;
+        letters.push(*letter);
+    }
+    Some(letters)
+}
+
+#[test]
+fn test_ocr() {
+    let lines = [
+        "#..#.####.#....#.....##..",
+        "#..#.#....#....#....#..#.",
+        "####.###..#....#....#..#.",
+        "#..#.#....#....#....#..#.",
+        "#..#.#....#....#....#..#.",
+        "#..#.####.####.####..##..",
+    ];
+    assert_eq!(recognize(&lines).as_deref(), Some("HELLO"));
+    let spaced: Vec<String> = lines.iter().map(|l| l.replace('.', " ")).collect();
+    assert_eq!(recognize(&spaced).as_deref(), Some("HELLO"));
+
+    let grid = Grid::from_text(&FONT_10[13].1.join("\n"), |c| c == '#').unwrap();
+    assert_eq!(recognize_grid(&grid).as_deref(), Some("X"));
+
+    assert_eq!(recognize(&["##.", "#.#"]), None);
+    assert_eq!(recognize(&lines.map(|l| l.replacen('#', ".", 1))), None);
+}
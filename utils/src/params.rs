@@ -0,0 +1,119 @@
+use anyhow::Context;
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::AocResult;
+
+/// A value a solution can be tuned with, like the row day 15 looks at
+///
+/// The default is what the real puzzle asks for. Examples and variant
+/// inputs override it with `--param name=value`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+impl Param {
+    #[must_use]
+    pub const fn new(name: &'static str, default: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            default,
+            help,
+        }
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={} ({})", self.name, self.default, self.help)
+    }
+}
+
+/// Values of a solution's parameters for one run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// Takes the declared defaults and replaces them with `overrides`
+    ///
+    /// Later overrides win over earlier ones.
+    ///
+    /// # Errors
+    ///
+    /// Fails if an override names a parameter that isn't declared
+    pub fn new<K, V>(declared: &[Param], overrides: &[(K, V)]) -> AocResult<Self>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut values: BTreeMap<String, String> = declared
+            .iter()
+            .map(|p| (p.name.to_string(), p.default.to_string()))
+            .collect();
+        for (name, value) in overrides {
+            let name = name.as_ref();
+            let Some(slot) = values.get_mut(name) else {
+                let known: Vec<String> = declared.iter().map(ToString::to_string).collect();
+                anyhow::bail!(
+                    "Unknown parameter `{name}`, expected one of: {}",
+                    if known.is_empty() {
+                        "none".to_string()
+                    } else {
+                        known.join(", ")
+                    }
+                );
+            };
+            *slot = value.as_ref().to_string();
+        }
+        Ok(Self(values))
+    }
+
+    /// Reads a parameter as `T`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the parameter isn't declared or its value isn't a valid `T`
+    pub fn get<T>(&self, name: &str) -> AocResult<T>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let value = self
+            .0
+            .get(name)
+            .with_context(|| format!("Parameter `{name}` isn't declared"))?;
+        value
+            .parse()
+            .with_context(|| format!("Invalid value `{value}` for parameter `{name}`"))
+    }
+}
+
+/// Parses `name=value` from the command line
+///
+/// # Errors
+///
+/// Fails if there is no `=`
+pub fn parse_param(arg: &str) -> Result<(String, String), String> {
+    arg.split_once('=')
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .ok_or_else(|| format!("`{arg}` is not of the form NAME=VALUE"))
+}
+
+#[test]
+fn test_params() {
+    const DECLARED: &[Param] = &[
+        Param::new("row", "2000000", "row to count"),
+        Param::new("max", "4000000", "size of the search area"),
+    ];
+    let params = Params::new(DECLARED, &[parse_param("row=10").unwrap()]).unwrap();
+    assert_eq!(params.get::<i64>("row").unwrap(), 10);
+    assert_eq!(params.get::<i64>("max").unwrap(), 4_000_000);
+    assert!(params.get::<u8>("max").is_err());
+    assert!(params.get::<i64>("column").is_err());
+
+    let error = Params::new(DECLARED, &[("column", "3")]).unwrap_err();
+    assert!(error.to_string().starts_with("Unknown parameter `column`"));
+    assert!(parse_param("row").is_err());
+}
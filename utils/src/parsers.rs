@@ -0,0 +1,198 @@
+use ndarray::Array2;
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, digit1, line_ending, one_of, space0, space1},
+    combinator::{map, map_res, opt, recognize},
+    error::{context, VerboseError},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair, tuple},
+    Parser,
+};
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::{grid::Grid, ParseResult};
+
+/// A number without a sign, like `42`
+///
+/// # Errors
+///
+/// Fails if the input doesn't start with a digit or the number doesn't fit in `T`
+pub fn unsigned<T: FromStr>(input: &str) -> ParseResult<'_, T> {
+    context("unsigned number", map_res(digit1, str::parse))(input)
+}
+
+/// A number with an optional sign, like `-7` or `+3`
+///
+/// # Errors
+///
+/// Fails if the input doesn't start with a number or it doesn't fit in `T`
+pub fn signed<T: FromStr>(input: &str) -> ParseResult<'_, T> {
+    let number = recognize(pair(opt(one_of("+-")), digit1));
+    context("signed number", map_res(number, str::parse))(input)
+}
+
+/// A blank line between two blocks, which may hold spaces
+///
+/// # Errors
+///
+/// Fails if the input doesn't start with two line endings
+pub fn blank_line(input: &str) -> ParseResult<'_, &str> {
+    recognize(tuple((line_ending, space0, line_ending)))(input)
+}
+
+/// One `item` per line
+///
+/// Lines can end with `\n` or `\r\n`. The newline at the end of the
+/// input isn't eaten, [`RunOptions::finish`](crate::RunOptions::finish)
+/// already ignores trailing whitespace.
+pub fn lines<'a, O, F>(item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(line_ending, item)
+}
+
+/// Blocks separated by blank lines, like the elves of day 1 or the
+/// monkeys of day 11
+pub fn blocks<'a, O, F>(block: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(blank_line, block)
+}
+
+/// Items separated by commas, with or without spaces around them
+pub fn comma_list<'a, O, F>(item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(delimited(space0, char(','), space0), item)
+}
+
+/// Items separated by spaces or tabs
+pub fn space_list<'a, O, F>(item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated_list1(space1, item)
+}
+
+/// A `key: value` line, which may be indented
+///
+/// The line ending isn't part of the header, so headers are joined with
+/// [`lines`] or `terminated(header(..), line_ending)`.
+pub fn header<'a, O, F>(key: &'static str, value: F) -> impl FnMut(&'a str) -> ParseResult<'a, O>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    let prefix = tuple((space0, tag(key), char(':'), space0));
+    context(key, preceded(prefix, value))
+}
+
+/// An inclusive range written as `start-end`, like the assignments of day 4
+///
+/// # Errors
+///
+/// Fails if the input doesn't start with two numbers joined by `-`
+pub fn range<T: FromStr>(input: &str) -> ParseResult<'_, RangeInclusive<T>> {
+    let bounds = separated_pair(unsigned, char('-'), unsigned);
+    context("range", map(bounds, |(start, end)| start..=end))(input)
+}
+
+/// A position written as `x=1, y=-2`, like the sensors of day 15
+///
+/// # Errors
+///
+/// Fails if the input doesn't start with both coordinates
+pub fn xy_pair<T: FromStr>(input: &str) -> ParseResult<'_, (T, T)> {
+    let x = preceded(tag("x="), signed);
+    let y = preceded(tag("y="), signed);
+    context("position", separated_pair(x, pair(char(','), space0), y))(input)
+}
+
+/// Lines of characters as a [`Grid`], like the heightmap of day 12, with
+/// every character turned into a cell by `cell`
+///
+/// # Errors
+///
+/// Fails if `cell` returns `None` for a character, or if a line is
+/// shorter or longer than the first one
+pub fn char_grid<'a, T, F>(mut cell: F) -> impl FnMut(&'a str) -> ParseResult<'a, Grid<T>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    move |input: &'a str| {
+        let rows = lines(take_till1(|c| c == '\r' || c == '\n'));
+        let mut grid = map_res(rows, |rows: Vec<&str>| {
+            let width = rows[0].chars().count();
+            let mut cells = Vec::with_capacity(rows.len() * width);
+            for row in &rows {
+                if row.chars().count() != width {
+                    return Err("rows of different lengths");
+                }
+                for c in row.chars() {
+                    cells.push(cell(c).ok_or("unexpected character")?);
+                }
+            }
+            Array2::from_shape_vec((rows.len(), width), cells)
+                .map(Grid::from)
+                .map_err(|_| "rows of different lengths")
+        });
+        grid(input)
+    }
+}
+
+/// Lines of single digits, like the trees of day 8, as `(row, column)` cells
+///
+/// # Errors
+///
+/// Fails if a line has something other than digits, or is shorter or
+/// longer than the first one
+pub fn digit_grid(input: &str) -> ParseResult<'_, Grid<u8>> {
+    let digit = |c: char| c.to_digit(10).and_then(|d| u8::try_from(d).ok());
+    context("digit grid", char_grid(digit))(input)
+}
+
+#[test]
+fn test_parsers() {
+    use nom::{character::complete::alpha1, combinator::all_consuming};
+
+    fn parsed<'a, O>(result: ParseResult<'a, O>) -> O {
+        let (remaining, value) = result.unwrap();
+        assert_eq!(remaining, "", "input left over");
+        value
+    }
+
+    assert_eq!(parsed(unsigned::<u8>("255")), 255);
+    assert!(unsigned::<u8>("256").is_err());
+    assert!(unsigned::<u32>("-1").is_err());
+    assert_eq!(parsed(signed::<i64>("-42")), -42);
+    assert_eq!(parsed(signed::<i8>("+7")), 7);
+
+    let elves = "1000\r\n2000\n\n4000\n  \r\n5000\n6000";
+    assert_eq!(
+        parsed(blocks(lines(unsigned::<u32>))(elves)),
+        [vec![1000, 2000], vec![4000], vec![5000, 6000]]
+    );
+    let (remaining, numbers) = lines(unsigned::<u32>)("1\n2\n").unwrap();
+    assert_eq!((remaining, numbers), ("\n", vec![1, 2]));
+
+    assert_eq!(parsed(comma_list(signed::<i32>)("1,-2 , 3")), [1, -2, 3]);
+    assert_eq!(parsed(space_list(alpha1)("ab  cd\tef")), ["ab", "cd", "ef"]);
+
+    let mut items = header("Starting items", comma_list(unsigned::<u64>));
+    assert_eq!(parsed(items("  Starting items: 79, 98")), [79, 98]);
+    let mut test = header("Test", preceded(tag("divisible by "), unsigned::<u64>));
+    assert!(test("  Tests: divisible by 3").is_err());
+
+    assert_eq!(parsed(range::<u32>("2-48")), 2..=48);
+    assert_eq!(parsed(xy_pair::<i64>("x=-2, y=15")), (-2, 15));
+    assert_eq!(parsed(xy_pair::<i64>("x=3,y=+4")), (3, 4));
+
+    let grid = parsed(digit_grid("303\r\n255"));
+    assert_eq!(grid, Grid::from(ndarray::array![[3, 0, 3], [2, 5, 5]]));
+    assert!(all_consuming(digit_grid)("303\n25").is_err());
+    assert!(all_consuming(digit_grid)("303\n2x5").is_err());
+    let grid = parsed(char_grid(|c| Some(c == '#'))("#.\n.#"));
+    assert_eq!(grid.render(|&rock| if rock { '#' } else { '.' }), "#.\n.#");
+}
@@ -0,0 +1,197 @@
+use serde::{Serialize, Serializer};
+
+use crate::{memory::Memory, timing::Timing, Answer, AocAnswer, AocResult};
+
+/// Everything a solution run produced, ready to be printed or serialized
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub parts: Vec<PartReport>,
+    pub timings: Vec<PhaseTiming>,
+    /// Failure that stopped every part from running, like a parse error
+    #[serde(serialize_with = "serialize_error")]
+    pub error: Option<anyhow::Error>,
+}
+
+/// Outcome of a single part
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// Extra output of the part, see [`AocAnswer::details`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    #[serde(serialize_with = "serialize_error")]
+    pub error: Option<anyhow::Error>,
+}
+
+/// Time spent in one phase, like parsing or a part
+#[derive(Debug, Serialize)]
+pub struct PhaseTiming {
+    pub phase: String,
+    #[serde(flatten)]
+    pub timing: Timing,
+    /// What the phase allocated, when run with `--mem`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+}
+
+/// What was measured of a phase: always its time, and its memory with `--mem`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measured {
+    pub timing: Timing,
+    pub memory: Option<Memory>,
+}
+
+impl From<Timing> for Measured {
+    fn from(timing: Timing) -> Self {
+        Self {
+            timing,
+            memory: None,
+        }
+    }
+}
+
+impl Report {
+    /// Creates an empty report for a day
+    #[must_use]
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            parts: Vec::new(),
+            timings: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Records how long a phase took, and what it allocated if that was measured
+    pub fn time(&mut self, phase: &str, measured: impl Into<Measured>) {
+        let Measured { timing, memory } = measured.into();
+        self.timings.push(PhaseTiming {
+            phase: phase.to_string(),
+            timing,
+            memory,
+        });
+    }
+
+    /// Records the result of a part
+    pub fn part<A: AocAnswer>(&mut self, part: u8, answer: AocResult<A>) {
+        let report = match answer {
+            Ok(answer) => PartReport {
+                part,
+                answer: Some(answer.answer()),
+                details: answer.details(),
+                error: None,
+            },
+            Err(e) => PartReport {
+                part,
+                answer: None,
+                details: None,
+                error: Some(e),
+            },
+        };
+        self.parts.push(report);
+    }
+
+    /// Records a failure that happened outside of a part
+    pub fn fail(&mut self, error: anyhow::Error) {
+        self.error = Some(error);
+    }
+
+    /// Removes the first failure from the report
+    pub fn take_error(&mut self) -> Option<anyhow::Error> {
+        self.error
+            .take()
+            .or_else(|| self.parts.iter_mut().find_map(|p| p.error.take()))
+    }
+
+    #[must_use]
+    pub fn has_error(&self) -> bool {
+        self.error.is_some() || self.parts.iter().any(|p| p.error.is_some())
+    }
+}
+
+/// Reads the day number out of a package name like `day-12`
+#[must_use]
+pub fn day_number(package: &str) -> u8 {
+    package
+        .trim_start_matches("day-")
+        .parse()
+        .unwrap_or_default()
+}
+
+impl Serialize for Timing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Nanos {
+            median_ns: u128,
+            min_ns: u128,
+            max_ns: u128,
+            runs: usize,
+        }
+        Nanos {
+            median_ns: self.median().as_nanos(),
+            min_ns: self.min().as_nanos(),
+            max_ns: self.max().as_nanos(),
+            runs: self.runs(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[allow(clippy::ref_option)]
+fn serialize_error<S: Serializer>(
+    error: &Option<anyhow::Error>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    error
+        .as_ref()
+        .map(|e| format!("{e:#}"))
+        .serialize(serializer)
+}
+
+#[test]
+fn test_report() {
+    let mut report = Report::new(day_number("day-10"));
+    report.part(1, Ok(13_140));
+    report.part(2, Ok(vec!["##..", "..##"]));
+    report.part::<u32>(2, Err(anyhow::anyhow!("no answer")));
+    report.time(
+        "parse",
+        Timing::new(vec![std::time::Duration::from_nanos(1_500)]),
+    );
+    report.time(
+        "part 1",
+        Measured {
+            timing: Timing::new(vec![std::time::Duration::from_nanos(900)]),
+            memory: Some(Memory {
+                allocations: 2,
+                bytes: 64,
+                peak_heap_bytes: 48,
+                peak_rss_bytes: Some(4_096),
+            }),
+        },
+    );
+    assert_eq!(
+        serde_json::to_value(&report).unwrap(),
+        serde_json::json!({
+            "day": 10,
+            "parts": [
+                { "part": 1, "answer": 13_140, "error": null },
+                { "part": 2, "answer": ["##..", "..##"], "error": null },
+                { "part": 2, "answer": null, "error": "no answer" },
+            ],
+            "timings": [
+                { "phase": "parse", "median_ns": 1_500, "min_ns": 1_500, "max_ns": 1_500, "runs": 1 },
+                {
+                    "phase": "part 1", "median_ns": 900, "min_ns": 900, "max_ns": 900, "runs": 1,
+                    "memory": { "allocations": 2, "bytes": 64, "peak_heap_bytes": 48, "peak_rss_bytes": 4_096 },
+                },
+            ],
+            "error": null,
+        })
+    );
+    assert!(report.has_error());
+    assert_eq!(report.take_error().unwrap().to_string(), "no answer");
+    assert!(!report.has_error());
+}
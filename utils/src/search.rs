@@ -0,0 +1,446 @@
+use num_traits::Zero;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Something that happened during a search, for callbacks to follow along
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step<'a, N, C> {
+    /// A node was reached for the first time, or by a cheaper way, from
+    /// `from` or as one of the starts
+    Visit {
+        node: &'a N,
+        from: Option<&'a N>,
+        cost: C,
+    },
+    /// The neighbors of a node are about to be looked at
+    Expand { node: &'a N, cost: C },
+}
+
+/// Everything a search found out about the graph
+#[derive(Debug, Clone)]
+pub struct Explored<N, C> {
+    /// Cost of the cheapest known way to every node reached
+    pub distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    /// The node that ended the search, if one was found
+    pub goal: Option<N>,
+    /// How many times a node was reached, including cheaper ways to
+    /// nodes already reached
+    pub visited: usize,
+    /// How many nodes had their neighbors looked at
+    pub expanded: usize,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Explored<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+            visited: 0,
+            expanded: 0,
+        }
+    }
+
+    /// Cost of the cheapest known way to `node`
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Cost of the way to the goal
+    pub fn cost(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// The way from a start to the goal, both included
+    #[must_use]
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// The cheapest known way from a start to `node`, both included
+    #[must_use]
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        let mut node = node;
+        while let Some(parent) = self.parents.get(node) {
+            path.push(parent.clone());
+            node = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// A node waiting to be expanded, ordered so the heap pops the lowest
+/// estimate first
+struct Frontier<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Frontier<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Frontier<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Frontier<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Frontier<N, C> {
+    /// Ties go to the node furthest from the start, which is usually the
+    /// closest to the goal
+    fn cmp(&self, other: &Self) -> Ordering {
+        Reverse(&self.estimate)
+            .cmp(&Reverse(&other.estimate))
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+type Callback<'a, N, C> = Box<dyn FnMut(Step<'_, N, C>) + 'a>;
+
+/// Runs searches, telling a callback about every step they take
+///
+/// The free functions of this module are searches without a callback.
+///
+/// ```
+/// use utils::search::{Searcher, Step};
+///
+/// let mut order = Vec::new();
+/// let explored = Searcher::new()
+///     .with_callback(|step| {
+///         if let Step::Expand { node, .. } = step {
+///             order.push(*node);
+///         }
+///     })
+///     .bfs([1], |&n| [n * 2, n + 1], |&n| n == 6);
+/// assert_eq!(explored.path(), Some(vec![1, 2, 3, 6]));
+/// assert_eq!(order, [1, 2, 4, 3, 8, 5]);
+/// ```
+pub struct Searcher<'a, N, C> {
+    callback: Option<Callback<'a, N, C>>,
+}
+
+impl<N, C> Default for Searcher<'_, N, C> {
+    fn default() -> Self {
+        Self { callback: None }
+    }
+}
+
+impl<'a, N: Eq + Hash + Clone, C: Copy + Ord + Zero> Searcher<'a, N, C> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { callback: None }
+    }
+
+    /// Calls `callback` on every step of the searches
+    #[must_use]
+    pub fn with_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(Step<'_, N, C>) + 'a,
+    {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    fn step(&mut self, step: Step<'_, N, C>) {
+        if let Some(callback) = &mut self.callback {
+            callback(step);
+        }
+    }
+
+    /// Cheapest ways from the starts, looking at the nodes with the
+    /// lowest cost plus `heuristic` first
+    ///
+    /// The search ends when a node passing `goal` is expanded, so
+    /// `|_| false` explores everything reachable. The heuristic must
+    /// never overestimate the cost to a goal, or the way found may not be
+    /// the cheapest.
+    pub fn astar<S, FN, IN, FH, FG>(
+        &mut self,
+        starts: S,
+        mut neighbors: FN,
+        mut heuristic: FH,
+        mut goal: FG,
+    ) -> Explored<N, C>
+    where
+        S: IntoIterator<Item = N>,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+        FG: FnMut(&N) -> bool,
+    {
+        let mut explored = Explored::new();
+        let mut frontier = BinaryHeap::new();
+        for start in starts {
+            if explored.distances.contains_key(&start) {
+                continue;
+            }
+            self.step(Step::Visit {
+                node: &start,
+                from: None,
+                cost: C::zero(),
+            });
+            explored.visited += 1;
+            explored.distances.insert(start.clone(), C::zero());
+            frontier.push(Frontier {
+                estimate: heuristic(&start),
+                cost: C::zero(),
+                node: start,
+            });
+        }
+        while let Some(Frontier { cost, node, .. }) = frontier.pop() {
+            if explored.distances[&node] < cost {
+                // Reached again more cheaply after this entry was pushed
+                continue;
+            }
+            if goal(&node) {
+                explored.goal = Some(node);
+                break;
+            }
+            self.step(Step::Expand { node: &node, cost });
+            explored.expanded += 1;
+            for (next, weight) in neighbors(&node) {
+                let cost = cost + weight;
+                if explored.distances.get(&next).is_some_and(|&d| d <= cost) {
+                    continue;
+                }
+                self.step(Step::Visit {
+                    node: &next,
+                    from: Some(&node),
+                    cost,
+                });
+                explored.visited += 1;
+                explored.distances.insert(next.clone(), cost);
+                explored.parents.insert(next.clone(), node.clone());
+                frontier.push(Frontier {
+                    estimate: cost + heuristic(&next),
+                    cost,
+                    node: next,
+                });
+            }
+        }
+        explored
+    }
+
+    /// Cheapest ways from the starts through weighted edges
+    ///
+    /// The search ends when a node passing `goal` is expanded, so
+    /// `|_| false` explores everything reachable.
+    pub fn dijkstra<S, FN, IN, FG>(&mut self, starts: S, neighbors: FN, goal: FG) -> Explored<N, C>
+    where
+        S: IntoIterator<Item = N>,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FG: FnMut(&N) -> bool,
+    {
+        self.astar(starts, neighbors, |_| C::zero(), goal)
+    }
+}
+
+impl<N: Eq + Hash + Clone> Searcher<'_, N, usize> {
+    /// Shortest ways from the starts, where every edge has a length of one
+    ///
+    /// The search ends when a node passing `goal` is expanded, so
+    /// `|_| false` explores everything reachable.
+    pub fn bfs<S, FN, IN, FG>(
+        &mut self,
+        starts: S,
+        mut neighbors: FN,
+        mut goal: FG,
+    ) -> Explored<N, usize>
+    where
+        S: IntoIterator<Item = N>,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+        FG: FnMut(&N) -> bool,
+    {
+        let mut explored = Explored::new();
+        let mut frontier = VecDeque::new();
+        for start in starts {
+            if explored.distances.contains_key(&start) {
+                continue;
+            }
+            self.step(Step::Visit {
+                node: &start,
+                from: None,
+                cost: 0,
+            });
+            explored.visited += 1;
+            explored.distances.insert(start.clone(), 0);
+            frontier.push_back(start);
+        }
+        while let Some(node) = frontier.pop_front() {
+            if goal(&node) {
+                explored.goal = Some(node);
+                break;
+            }
+            let cost = explored.distances[&node];
+            self.step(Step::Expand { node: &node, cost });
+            explored.expanded += 1;
+            for next in neighbors(&node) {
+                if explored.distances.contains_key(&next) {
+                    continue;
+                }
+                self.step(Step::Visit {
+                    node: &next,
+                    from: Some(&node),
+                    cost: cost + 1,
+                });
+                explored.visited += 1;
+                explored.distances.insert(next.clone(), cost + 1);
+                explored.parents.insert(next.clone(), node.clone());
+                frontier.push_back(next);
+            }
+        }
+        explored
+    }
+}
+
+/// Breadth first search from one node, see [`Searcher::bfs`]
+pub fn bfs<N, FN, IN, FG>(start: N, neighbors: FN, goal: FG) -> Explored<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    Searcher::new().bfs([start], neighbors, goal)
+}
+
+/// Breadth first search from all the starts at once, which finds the
+/// closest start to every node
+pub fn multi_bfs<N, S, FN, IN, FG>(starts: S, neighbors: FN, goal: FG) -> Explored<N, usize>
+where
+    N: Eq + Hash + Clone,
+    S: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    Searcher::new().bfs(starts, neighbors, goal)
+}
+
+/// Dijkstra's search from one node, see [`Searcher::dijkstra`]
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, neighbors: FN, goal: FG) -> Explored<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Zero,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    Searcher::new().dijkstra([start], neighbors, goal)
+}
+
+/// A* search from one node, see [`Searcher::astar`]
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    neighbors: FN,
+    heuristic: FH,
+    goal: FG,
+) -> Explored<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Zero,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    Searcher::new().astar([start], neighbors, heuristic, goal)
+}
+
+#[test]
+fn test_search() {
+    use ndarray::array;
+
+    // 0 is open and 1 is a wall, the weights are the digits plus one
+    let maze = array![[0, 0, 0, 0], [1, 1, 0, 1], [0, 0, 0, 0], [0, 1, 1, 0]];
+    let steps = |&(r, c): &(usize, usize)| {
+        [
+            (r.wrapping_sub(1), c),
+            (r + 1, c),
+            (r, c.wrapping_sub(1)),
+            (r, c + 1),
+        ]
+        .into_iter()
+        .filter(|&p| maze.get(p) == Some(&0))
+        .collect::<Vec<_>>()
+    };
+
+    let explored = bfs((0, 0), steps, |&p| p == (3, 0));
+    assert_eq!(explored.cost(), Some(7));
+    assert_eq!(
+        explored.path().unwrap(),
+        [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (2, 1),
+            (2, 0),
+            (3, 0)
+        ]
+    );
+    assert_eq!(explored.path_to(&(1, 0)), None);
+    let everything = bfs((0, 0), steps, |_| false);
+    assert_eq!(everything.goal, None);
+    assert_eq!(everything.distances.len(), 11);
+    assert_eq!(everything.visited, 11);
+    assert_eq!(everything.expanded, 11);
+    assert_eq!(everything.distance(&(3, 3)), Some(6));
+
+    let nearest = multi_bfs([(0, 0), (3, 3)], steps, |_| false);
+    assert_eq!(nearest.distance(&(2, 0)), Some(4));
+    assert_eq!(nearest.distance(&(2, 3)), Some(1));
+    assert_eq!(nearest.path_to(&(2, 2)).unwrap()[0], (3, 3));
+
+    // A slow road straight to the goal and a fast one around it
+    let roads = |&n: &char| match n {
+        'a' => vec![('b', 10), ('c', 1)],
+        'c' => vec![('d', 1)],
+        'd' => vec![('b', 1), ('e', 20)],
+        'b' => vec![('e', 1)],
+        _ => vec![],
+    };
+    let explored = dijkstra('a', roads, |&n| n == 'e');
+    assert_eq!(explored.cost(), Some(4));
+    assert_eq!(explored.path().unwrap(), ['a', 'c', 'd', 'b', 'e']);
+    assert_eq!(explored.visited, 7);
+
+    let weighted = |&(r, c): &(usize, usize)| steps(&(r, c)).into_iter().map(|p| (p, 1));
+    let manhattan = |&(r, c): &(usize, usize)| r.abs_diff(3) + c.abs_diff(0);
+    let explored = astar((0, 0), weighted, manhattan, |&p| p == (3, 0));
+    assert_eq!(explored.cost(), Some(7));
+    let everything = dijkstra((0, 0), weighted, |_| false);
+    assert!(explored.expanded < everything.expanded);
+
+    let mut visits = Vec::new();
+    let mut expanded = 0;
+    let explored = Searcher::new()
+        .with_callback(|step| match step {
+            Step::Visit { node, from, cost } => visits.push((*node, from.copied(), cost)),
+            Step::Expand { .. } => expanded += 1,
+        })
+        .dijkstra(['a'], roads, |_| false);
+    assert_eq!(explored.expanded, expanded);
+    assert_eq!(visits.len(), explored.visited);
+    assert_eq!(
+        visits[..3],
+        [('a', None, 0), ('b', Some('a'), 10), ('c', Some('a'), 1)]
+    );
+    assert!(visits.contains(&('b', Some('d'), 3)));
+}
@@ -0,0 +1,590 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use anyhow::Context;
+
+use crate::{
+    memory,
+    params::{Param, Params},
+    report::Report,
+    svg::Svg,
+    viz::Visualize,
+    Answer, AocAnswer, AocResult, ParseResult, RunOptions, SolutionPart,
+};
+
+/// How part 2 sees the input of part 1, see [`Solution::shared`]
+pub type Shared<I, I2> = fn(&I) -> &I2;
+
+/// A day's puzzle: how to parse the input and how to answer both parts
+pub trait Solution {
+    /// Input of part 1
+    type Input;
+    /// Input of part 2, the same as [`Solution::Input`] unless part 2 has
+    /// a parser of its own
+    type Input2;
+    type Answer1: AocAnswer;
+    type Answer2: AocAnswer;
+
+    /// Day of December the puzzle is from
+    fn day(&self) -> u8;
+
+    /// Parameters the parts read from their [`Params`]
+    fn params(&self) -> &[Param] {
+        &[]
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Input>;
+
+    /// Parser for part 2, used unless [`Solution::shared`] says part 2
+    /// reads the output of [`Solution::parse`]
+    fn parse2<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Input2>;
+
+    /// How part 2 sees the output of [`Solution::parse`], for days where
+    /// both parts read the input the same way
+    ///
+    /// Returns `None` by default, in which case part 2 gets the output of
+    /// [`Solution::parse2`].
+    fn shared(&self) -> Option<Shared<Self::Input, Self::Input2>> {
+        None
+    }
+
+    /// # Errors
+    ///
+    /// Fails if the input has no answer
+    fn part1(&self, input: &Self::Input, params: &Params) -> AocResult<Self::Answer1>;
+
+    /// # Errors
+    ///
+    /// Fails if the input has no answer
+    fn part2(&self, input: &Self::Input2, params: &Params) -> AocResult<Self::Answer2>;
+
+    /// Simulation of a part to watch with `--visualize`
+    ///
+    /// Returns `None` by default, for days that aren't simulations.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the simulation can't be set up, like with a bad parameter
+    fn visualize(
+        &self,
+        input: PartInput<&Self::Input, &Self::Input2>,
+        params: &Params,
+    ) -> AocResult<Option<Box<dyn Visualize>>> {
+        let _ = (input, params);
+        Ok(None)
+    }
+
+    /// Picture of a part's answer to write with `--svg`
+    ///
+    /// Returns `None` by default, for days without one.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the picture can't be drawn, like when the input has no answer
+    fn svg(
+        &self,
+        input: PartInput<&Self::Input, &Self::Input2>,
+        params: &Params,
+    ) -> AocResult<Option<Svg>> {
+        let _ = (input, params);
+        Ok(None)
+    }
+}
+
+/// The input of one part, for what can be done with either part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartInput<I1, I2> {
+    One(I1),
+    Two(I2),
+}
+
+impl<I1, I2> PartInput<I1, I2> {
+    /// Number of the part the input is for
+    #[must_use]
+    pub const fn part(&self) -> u8 {
+        match self {
+            Self::One(_) => 1,
+            Self::Two(_) => 2,
+        }
+    }
+}
+
+/// Parses the input and runs the parts picked in `options`
+///
+/// Failures are recorded in the report rather than returned, so the
+/// parts that did work still get their answers printed.
+pub fn run<S: Solution + ?Sized>(solution: &S, input: &str, options: &RunOptions) -> Report {
+    let mut report = Report::new(solution.day());
+    if options.mem && !memory::is_counting() {
+        report.fail(anyhow::anyhow!(
+            "`--mem` needs the counting allocator, build with `--features mem`"
+        ));
+        return report;
+    }
+    let params = match Params::new(solution.params(), &options.params) {
+        Ok(params) => params,
+        Err(e) => {
+            report.fail(e);
+            return report;
+        }
+    };
+    let run_part1 = options.should_run(SolutionPart::PartOne);
+    let run_part2 = options.should_run(SolutionPart::PartTwo);
+    if let Some(shared) = solution.shared() {
+        let (parsed, time) = options.time(|| solution.parse(input));
+        report.time("parse", time);
+        match options.finish(input, parsed) {
+            Ok(parsed) => {
+                if run_part1 {
+                    run_part(&mut report, options, 1, || solution.part1(&parsed, &params));
+                }
+                if run_part2 {
+                    let parsed = shared(&parsed);
+                    run_part(&mut report, options, 2, || solution.part2(parsed, &params));
+                }
+            }
+            Err(e) => report.fail(e),
+        }
+        return report;
+    }
+    if run_part1 {
+        let (parsed, time) = options.time(|| solution.parse(input));
+        report.time("parse 1", time);
+        match options.finish(input, parsed) {
+            Ok(parsed) => run_part(&mut report, options, 1, || solution.part1(&parsed, &params)),
+            Err(e) => report.part::<Answer>(1, Err(e)),
+        }
+    }
+    if run_part2 {
+        let (parsed, time) = options.time(|| solution.parse2(input));
+        report.time("parse 2", time);
+        match options.finish(input, parsed) {
+            Ok(parsed) => run_part(&mut report, options, 2, || solution.part2(&parsed, &params)),
+            Err(e) => report.part::<Answer>(2, Err(e)),
+        }
+    }
+    report
+}
+
+/// Parses the input and sets up the simulation of the part picked in `options`
+///
+/// Part 1 is simulated unless `options` asks for part 2 alone.
+///
+/// # Errors
+///
+/// Fails if the input doesn't parse or the day has nothing to watch
+pub fn visualize<S: Solution + ?Sized>(
+    solution: &S,
+    input: &str,
+    options: &RunOptions,
+) -> AocResult<Box<dyn Visualize>> {
+    let day = solution.day();
+    with_part(solution, input, options, |input, params| {
+        solution
+            .visualize(input, params)?
+            .with_context(|| format!("Day {day} has nothing to visualize"))
+    })
+}
+
+/// Parses the input and draws the answer of the part picked in `options`
+///
+/// Part 1 is drawn unless `options` asks for part 2 alone.
+///
+/// # Errors
+///
+/// Fails if the input doesn't parse or the day has nothing to draw
+pub fn svg<S: Solution + ?Sized>(
+    solution: &S,
+    input: &str,
+    options: &RunOptions,
+) -> AocResult<Svg> {
+    let day = solution.day();
+    with_part(solution, input, options, |input, params| {
+        solution
+            .svg(input, params)?
+            .with_context(|| format!("Day {day} has nothing to draw"))
+    })
+}
+
+/// Parses the input for the single part picked in `options`, part 1 by
+/// default, and hands it to `f`
+fn with_part<S, T, F>(solution: &S, input: &str, options: &RunOptions, f: F) -> AocResult<T>
+where
+    S: Solution + ?Sized,
+    F: FnOnce(PartInput<&S::Input, &S::Input2>, &Params) -> AocResult<T>,
+{
+    let params = Params::new(solution.params(), &options.params)?;
+    if options.part != SolutionPart::PartTwo {
+        let parsed = options.finish(input, solution.parse(input))?;
+        return f(PartInput::One(&parsed), &params);
+    }
+    if let Some(shared) = solution.shared() {
+        let parsed = options.finish(input, solution.parse(input))?;
+        f(PartInput::Two(shared(&parsed)), &params)
+    } else {
+        let parsed = options.finish(input, solution.parse2(input))?;
+        f(PartInput::Two(&parsed), &params)
+    }
+}
+
+fn run_part<A, F>(report: &mut Report, options: &RunOptions, part: u8, f: F)
+where
+    A: AocAnswer,
+    F: FnMut() -> AocResult<A>,
+{
+    let (answer, time) = options.time(f);
+    report.time(&format!("part {part}"), time);
+    report.part(part, answer);
+}
+
+/// Object safe side of [`Solution`], so days with different types can
+/// be stored together
+pub trait Runnable {
+    fn day(&self) -> u8;
+
+    /// Runs the solution on `input`, see [`run`]
+    fn run(&self, input: &str, options: &RunOptions) -> Report;
+}
+
+impl<S: Solution> Runnable for S {
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn run(&self, input: &str, options: &RunOptions) -> Report {
+        run(self, input, options)
+    }
+}
+
+type Parser<I> = for<'a> fn(&'a str) -> ParseResult<'a, I>;
+type Part<I, A> = fn(&I) -> AocResult<A>;
+type ParamPart<I, A> = fn(&I, &Params) -> AocResult<A>;
+type VisualizeFn<I> = fn(&I, u8, &Params) -> AocResult<Box<dyn Visualize>>;
+type SvgFn<I> = fn(&I, u8, &Params) -> AocResult<Svg>;
+
+/// A part function, with or without access to the parameters
+enum PartFn<I, A> {
+    Plain(Part<I, A>),
+    WithParams(ParamPart<I, A>),
+}
+
+impl<I, A> PartFn<I, A> {
+    fn call(&self, input: &I, params: &Params) -> AocResult<A> {
+        match self {
+            Self::Plain(part) => part(input),
+            Self::WithParams(part) => part(input, params),
+        }
+    }
+}
+
+/// [`Solution`] made out of plain `parse`, `part1` and `part2` functions
+pub struct FnSolution<I, A1, A2> {
+    day: u8,
+    parse: Parser<I>,
+    part1: PartFn<I, A1>,
+    part2: PartFn<I, A2>,
+    params: &'static [Param],
+    visualize: Option<VisualizeFn<I>>,
+    svg: Option<SvgFn<I>>,
+}
+
+impl<I, A1, A2> FnSolution<I, A1, A2> {
+    #[must_use]
+    pub fn new(day: u8, parse: Parser<I>, part1: Part<I, A1>, part2: Part<I, A2>) -> Self {
+        Self {
+            day,
+            parse,
+            part1: PartFn::Plain(part1),
+            part2: PartFn::Plain(part2),
+            params: &[],
+            visualize: None,
+            svg: None,
+        }
+    }
+
+    /// Creates a solution whose parts read the declared `params`
+    #[must_use]
+    pub fn with_params(
+        day: u8,
+        parse: Parser<I>,
+        part1: ParamPart<I, A1>,
+        part2: ParamPart<I, A2>,
+        params: &'static [Param],
+    ) -> Self {
+        Self {
+            day,
+            parse,
+            part1: PartFn::WithParams(part1),
+            part2: PartFn::WithParams(part2),
+            params,
+            visualize: None,
+            svg: None,
+        }
+    }
+
+    /// Lets the solution be watched with `--visualize`
+    ///
+    /// `visualize` gets the parsed input and the part to simulate.
+    #[must_use]
+    pub fn with_visualize(mut self, visualize: VisualizeFn<I>) -> Self {
+        self.visualize = Some(visualize);
+        self
+    }
+
+    /// Lets the solution draw its answers with `--svg`
+    ///
+    /// `svg` gets the parsed input and the part to draw.
+    #[must_use]
+    pub fn with_svg(mut self, svg: SvgFn<I>) -> Self {
+        self.svg = Some(svg);
+        self
+    }
+}
+
+impl<I, A1, A2> Solution for FnSolution<I, A1, A2>
+where
+    A1: AocAnswer,
+    A2: AocAnswer,
+{
+    type Input = I;
+    type Input2 = I;
+    type Answer1 = A1;
+    type Answer2 = A2;
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn params(&self) -> &[Param] {
+        self.params
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> ParseResult<'a, I> {
+        (self.parse)(input)
+    }
+
+    fn parse2<'a>(&self, input: &'a str) -> ParseResult<'a, I> {
+        (self.parse)(input)
+    }
+
+    fn shared(&self) -> Option<Shared<I, I>> {
+        Some(|input| input)
+    }
+
+    fn part1(&self, input: &I, params: &Params) -> AocResult<A1> {
+        self.part1.call(input, params)
+    }
+
+    fn part2(&self, input: &I, params: &Params) -> AocResult<A2> {
+        self.part2.call(input, params)
+    }
+
+    fn visualize(
+        &self,
+        input: PartInput<&I, &I>,
+        params: &Params,
+    ) -> AocResult<Option<Box<dyn Visualize>>> {
+        let part = input.part();
+        let (PartInput::One(input) | PartInput::Two(input)) = input;
+        self.visualize
+            .map(|visualize| visualize(input, part, params))
+            .transpose()
+    }
+
+    fn svg(&self, input: PartInput<&I, &I>, params: &Params) -> AocResult<Option<Svg>> {
+        let part = input.part();
+        let (PartInput::One(input) | PartInput::Two(input)) = input;
+        self.svg.map(|svg| svg(input, part, params)).transpose()
+    }
+}
+
+/// [`Solution`] where each part has its own parser
+pub struct SplitFnSolution<I1, I2, A1, A2> {
+    day: u8,
+    parse1: Parser<I1>,
+    parse2: Parser<I2>,
+    part1: Part<I1, A1>,
+    part2: Part<I2, A2>,
+}
+
+impl<I1, I2, A1, A2> SplitFnSolution<I1, I2, A1, A2> {
+    #[must_use]
+    pub fn new(
+        day: u8,
+        parse1: Parser<I1>,
+        parse2: Parser<I2>,
+        part1: Part<I1, A1>,
+        part2: Part<I2, A2>,
+    ) -> Self {
+        Self {
+            day,
+            parse1,
+            parse2,
+            part1,
+            part2,
+        }
+    }
+}
+
+impl<I1, I2, A1, A2> Solution for SplitFnSolution<I1, I2, A1, A2>
+where
+    A1: AocAnswer,
+    A2: AocAnswer,
+{
+    type Input = I1;
+    type Input2 = I2;
+    type Answer1 = A1;
+    type Answer2 = A2;
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> ParseResult<'a, I1> {
+        (self.parse1)(input)
+    }
+
+    fn parse2<'a>(&self, input: &'a str) -> ParseResult<'a, I2> {
+        (self.parse2)(input)
+    }
+
+    fn part1(&self, input: &I1, _params: &Params) -> AocResult<A1> {
+        (self.part1)(input)
+    }
+
+    fn part2(&self, input: &I2, _params: &Params) -> AocResult<A2> {
+        (self.part2)(input)
+    }
+}
+
+/// Solutions looked up by their day
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<u8, Box<dyn Runnable>>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a solution, replacing any earlier one for the same day
+    pub fn register(&mut self, solution: impl Runnable + 'static) -> &mut Self {
+        self.solutions.insert(solution.day(), Box::new(solution));
+        self
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u8) -> Option<&dyn Runnable> {
+        self.solutions.get(&day).map(AsRef::as_ref)
+    }
+
+    /// Registered days in ascending order
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.solutions.keys().copied()
+    }
+
+    /// Registered solutions in ascending order of their day
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Runnable> {
+        self.solutions.values().map(AsRef::as_ref)
+    }
+}
+
+/// Days picked on the command line, like `3`, `1..=15` or `1,4..7`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaySet(BTreeSet<u8>);
+
+impl DaySet {
+    /// Every day of Advent of Code
+    #[must_use]
+    pub fn all() -> Self {
+        Self((1..=25).collect())
+    }
+
+    #[must_use]
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+
+    /// Picked days in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |d: &str| match d.trim().parse() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(format!("`{d}` is not a day from 1 to 25")),
+        };
+        let mut days = BTreeSet::new();
+        for item in s.split(',') {
+            if let Some((start, end)) = item.split_once("..=") {
+                days.extend(day(start)?..=day(end)?);
+            } else if let Some((start, end)) = item.split_once("..") {
+                days.extend(day(start)?..day(end)?);
+            } else {
+                days.insert(day(item)?);
+            }
+        }
+        Ok(Self(days))
+    }
+}
+
+#[test]
+fn test_registry() {
+    use nom::character::complete::{i32, line_ending};
+    use nom::multi::separated_list1;
+
+    fn parse(input: &str) -> ParseResult<'_, Vec<i32>> {
+        separated_list1(line_ending, i32)(input)
+    }
+    #[allow(clippy::unnecessary_wraps, clippy::ptr_arg)]
+    fn sum(input: &Vec<i32>) -> AocResult<i32> {
+        Ok(input.iter().sum())
+    }
+    #[allow(clippy::unnecessary_wraps, clippy::ptr_arg)]
+    fn max(input: &Vec<i32>) -> AocResult<i32> {
+        Ok(input.iter().copied().max().unwrap_or_default())
+    }
+    #[allow(clippy::unnecessary_wraps, clippy::ptr_arg)]
+    fn count(input: &Vec<i32>) -> AocResult<usize> {
+        Ok(input.len())
+    }
+
+    let mut registry = Registry::new();
+    registry
+        .register(SplitFnSolution::new(7, parse, parse, sum, count))
+        .register(FnSolution::new(3, parse, sum, max));
+    assert_eq!(registry.days().collect::<Vec<_>>(), [3, 7]);
+
+    let options = RunOptions::default();
+    let answers = |day| {
+        let report = registry.get(day).unwrap().run("1\n5\n2", &options);
+        report
+            .parts
+            .iter()
+            .map(|p| p.answer.clone().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(answers(3), ["8", "5"]);
+    assert_eq!(answers(7), ["8", "3"]);
+
+    let report = registry.get(3).unwrap().run("x", &options);
+    assert!(report.error.is_some() && report.parts.is_empty());
+    let report = registry.get(7).unwrap().run("x", &options);
+    assert!(report.error.is_none() && report.parts.iter().all(|p| p.error.is_some()));
+    let phases: Vec<_> = report.timings.iter().map(|t| t.phase.as_str()).collect();
+    assert_eq!(phases, ["parse 1", "parse 2"]);
+    assert!(registry.get(4).is_none());
+
+    let days: DaySet = "1..=3,7,10..12".parse().unwrap();
+    assert_eq!(days.iter().collect::<Vec<_>>(), [1, 2, 3, 7, 10, 11]);
+    assert!("0..=3".parse::<DaySet>().is_err() && "x".parse::<DaySet>().is_err());
+}
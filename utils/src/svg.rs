@@ -0,0 +1,319 @@
+use anyhow::Context;
+use ndarray::Array2;
+use std::{
+    fmt::{self, Display},
+    path::Path,
+};
+
+use crate::{geom::Point2, AocResult};
+
+/// How a shape is painted, with colors in any form SVG accepts
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    pub fill: Option<String>,
+    pub stroke: Option<String>,
+    pub stroke_width: f64,
+    pub opacity: f64,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: None,
+            stroke: None,
+            stroke_width: 0.1,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl Style {
+    /// Filled with `color`, without an outline
+    #[must_use]
+    pub fn fill(color: impl Into<String>) -> Self {
+        Self {
+            fill: Some(color.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Outlined with `color` at `width`, without a fill
+    #[must_use]
+    pub fn stroke(color: impl Into<String>, width: f64) -> Self {
+        Self {
+            stroke: Some(color.into()),
+            stroke_width: width,
+            ..Self::default()
+        }
+    }
+
+    /// Adds an outline to the style
+    #[must_use]
+    pub fn with_stroke(mut self, color: impl Into<String>, width: f64) -> Self {
+        self.stroke = Some(color.into());
+        self.stroke_width = width;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, r#"fill="{}""#, self.fill.as_deref().unwrap_or("none"))?;
+        if let Some(stroke) = &self.stroke {
+            write!(
+                f,
+                r#" stroke="{stroke}" stroke-width="{}""#,
+                self.stroke_width
+            )?;
+        }
+        if self.opacity < 1.0 {
+            write!(f, r#" opacity="{}""#, self.opacity)?;
+        }
+        Ok(())
+    }
+}
+
+/// A shape in the coordinates of the puzzle, where a cell is one unit wide
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// The unit square with its top left corner at the point
+    Cell(Point2<f64>),
+    /// A rectangle from its top left corner, with its width and height
+    Rect(Point2<f64>, f64, f64),
+    /// Straight lines through the points
+    Polyline(Vec<Point2<f64>>),
+    /// The points at most `radius` steps away from the center, as
+    /// sensors reach on day 15
+    Diamond(Point2<f64>, f64),
+    /// A circle around a point, with its radius
+    Circle(Point2<f64>, f64),
+    /// Text with the middle of its baseline at the point
+    Text(Point2<f64>, String),
+}
+
+impl Shape {
+    /// Straight lines through the centers of the cells
+    #[must_use]
+    pub fn path<I: IntoIterator<Item = Point2<usize>>>(cells: I) -> Self {
+        Self::Polyline(cells.into_iter().map(center).collect())
+    }
+}
+
+/// Center of a cell
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn center(cell: Point2<usize>) -> Point2<f64> {
+    Point2::new(cell.x as f64 + 0.5, cell.y as f64 + 0.5)
+}
+
+/// Escapes the characters XML gives a meaning to
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A standalone SVG document built from shapes
+///
+/// ```
+/// use utils::{geom::Point2, svg::{Shape, Style, Svg}};
+///
+/// let mut svg = Svg::new(Point2::new(0.0, 0.0), 8.0, 5.0);
+/// svg.add(&Shape::Cell(Point2::new(2.0, 3.0)), &Style::fill("red"));
+/// assert!(svg.to_string().contains(r#"<rect x="2" y="3""#));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    origin: Point2<f64>,
+    width: f64,
+    height: f64,
+    scale: f64,
+    background: Option<String>,
+    title: Option<String>,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    /// Creates an empty picture of the area from `origin` on
+    ///
+    /// A unit is drawn ten pixels wide unless the scale is changed.
+    #[must_use]
+    pub const fn new(origin: Point2<f64>, width: f64, height: f64) -> Self {
+        Self {
+            origin,
+            width,
+            height,
+            scale: 10.0,
+            background: None,
+            title: None,
+            elements: Vec::new(),
+        }
+    }
+
+    /// Sets how many pixels wide a unit is drawn
+    #[must_use]
+    pub const fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Picks the scale that makes the picture `pixels` wide
+    #[must_use]
+    pub fn with_width(self, pixels: f64) -> Self {
+        let scale = pixels / self.width;
+        self.with_scale(scale)
+    }
+
+    #[must_use]
+    pub fn with_background(mut self, color: impl Into<String>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Sets the title viewers show for the picture
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Draws a shape over the ones already drawn
+    pub fn add(&mut self, shape: &Shape, style: &Style) -> &mut Self {
+        let element = match shape {
+            Shape::Cell(p) => format!(r#"<rect x="{}" y="{}" width="1" height="1""#, p.x, p.y),
+            Shape::Rect(p, width, height) => format!(
+                r#"<rect x="{}" y="{}" width="{width}" height="{height}""#,
+                p.x, p.y
+            ),
+            Shape::Polyline(points) => {
+                let points: Vec<String> =
+                    points.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+                format!(r#"<polyline points="{}""#, points.join(" "))
+            }
+            Shape::Diamond(c, r) => format!(
+                r#"<polygon points="{},{} {},{} {},{} {},{}""#,
+                c.x,
+                c.y - r,
+                c.x + r,
+                c.y,
+                c.x,
+                c.y + r,
+                c.x - r,
+                c.y
+            ),
+            Shape::Circle(c, r) => format!(r#"<circle cx="{}" cy="{}" r="{r}""#, c.x, c.y),
+            Shape::Text(p, _) => format!(
+                r#"<text x="{}" y="{}" text-anchor="middle" font-family="monospace" font-size="1""#,
+                p.x, p.y
+            ),
+        };
+        let element = match shape {
+            Shape::Text(_, text) => format!("{element} {style}>{}</text>", escape(text)),
+            _ => format!("{element} {style}/>"),
+        };
+        self.elements.push(element);
+        self
+    }
+
+    /// Draws every cell of a grid that `style` gives a style to
+    ///
+    /// Cells are placed by their `(row, column)` index, so the grid
+    /// lines up with the puzzle's own map.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn grid<T, F>(&mut self, grid: &Array2<T>, mut style: F) -> &mut Self
+    where
+        F: FnMut(&T) -> Option<Style>,
+    {
+        for ((row, col), value) in grid.indexed_iter() {
+            if let Some(style) = style(value) {
+                self.add(&Shape::Cell(Point2::new(col as f64, row as f64)), &style);
+            }
+        }
+        self
+    }
+
+    /// Writes the document to a file
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file can't be written
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("Unable to write {}", path.display()))
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            origin,
+            width,
+            height,
+            scale,
+            ..
+        } = self;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {width} {height}" width="{}" height="{}">"#,
+            origin.x,
+            origin.y,
+            (width * scale).round(),
+            (height * scale).round()
+        )?;
+        if let Some(title) = &self.title {
+            writeln!(f, "<title>{}</title>", escape(title))?;
+        }
+        if let Some(background) = &self.background {
+            writeln!(
+                f,
+                r#"<rect x="{}" y="{}" width="{width}" height="{height}" fill="{background}"/>"#,
+                origin.x, origin.y
+            )?;
+        }
+        for element in &self.elements {
+            writeln!(f, "{element}")?;
+        }
+        write!(f, "</svg>")
+    }
+}
+
+#[test]
+fn test_svg() {
+    let mut svg = Svg::new(Point2::new(-1.0, 0.0), 4.0, 2.0)
+        .with_background("black")
+        .with_title("a < b");
+    svg.grid(&ndarray::array![[true, false]], |&b| {
+        b.then(|| Style::fill("green"))
+    })
+    .add(
+        &Shape::path([Point2::new(0, 0), Point2::new(1, 1)]),
+        &Style::stroke("red", 0.2),
+    )
+    .add(
+        &Shape::Diamond(Point2::new(1.0, 1.0), 1.5),
+        &Style::fill("blue").with_opacity(0.5),
+    )
+    .add(
+        &Shape::Text(Point2::new(0.5, 2.0), "S".into()),
+        &Style::fill("white"),
+    );
+    assert_eq!(
+        svg.to_string(),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-1 0 4 2" width="40" height="20">
+<title>a &lt; b</title>
+<rect x="-1" y="0" width="4" height="2" fill="black"/>
+<rect x="0" y="0" width="1" height="1" fill="green"/>
+<polyline points="0.5,0.5 1.5,1.5" fill="none" stroke="red" stroke-width="0.2"/>
+<polygon points="1,-0.5 2.5,1 1,2.5 -0.5,1" fill="blue" opacity="0.5"/>
+<text x="0.5" y="2" text-anchor="middle" font-family="monospace" font-size="1" fill="white">S</text>
+</svg>"#
+    );
+    assert_eq!(svg.clone().with_width(100.0).scale, 25.0);
+}
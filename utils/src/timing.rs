@@ -0,0 +1,126 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+/// Durations measured for one phase of a solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    /// Every measured run, sorted from fastest to slowest
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    #[must_use]
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self { samples }
+    }
+
+    #[must_use]
+    pub fn min(&self) -> Duration {
+        self.samples.first().copied().unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn median(&self) -> Duration {
+        self.samples
+            .get(self.samples.len() / 2)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn max(&self) -> Duration {
+        self.samples.last().copied().unwrap_or_default()
+    }
+
+    /// Number of measured runs
+    #[must_use]
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.runs() > 1 {
+            write!(
+                f,
+                "{} (min {}, max {}, {} runs)",
+                Human(self.median()),
+                Human(self.min()),
+                Human(self.max()),
+                self.runs()
+            )
+        } else {
+            write!(f, "{}", Human(self.median()))
+        }
+    }
+}
+
+/// Displays a duration with the unit that fits its size
+#[derive(Debug, Clone, Copy)]
+pub struct Human(pub Duration);
+
+impl Display for Human {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        #[allow(clippy::cast_precision_loss)]
+        let value = nanos as f64;
+        if nanos < 1_000 {
+            write!(f, "{nanos}ns")
+        } else if nanos < 1_000_000 {
+            write!(f, "{:.2}µs", value / 1e3)
+        } else if nanos < 1_000_000_000 {
+            write!(f, "{:.2}ms", value / 1e6)
+        } else {
+            write!(f, "{:.2}s", value / 1e9)
+        }
+    }
+}
+
+/// Runs `f` `runs` times, returning the last result and the measured durations
+///
+/// `f` is always run at least once.
+pub fn time<T, F>(runs: usize, mut f: F) -> (T, Timing)
+where
+    F: FnMut() -> T,
+{
+    let mut samples = Vec::with_capacity(runs.max(1));
+    let mut run = || {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+        result
+    };
+    let mut result = run();
+    for _ in 1..runs {
+        result = run();
+    }
+    (result, Timing::new(samples))
+}
+
+#[test]
+fn test_timing() {
+    let ms = Duration::from_millis;
+    let timing = Timing::new(vec![ms(5), ms(1), ms(3)]);
+    assert_eq!(
+        (timing.min(), timing.median(), timing.max()),
+        (ms(1), ms(3), ms(5))
+    );
+    assert_eq!(
+        timing.to_string(),
+        "3.00ms (min 1.00ms, max 5.00ms, 3 runs)"
+    );
+    assert_eq!(Human(Duration::from_nanos(999)).to_string(), "999ns");
+    assert_eq!(Human(Duration::from_nanos(12_345)).to_string(), "12.35µs");
+    assert_eq!(Human(Duration::from_millis(2_500)).to_string(), "2.50s");
+
+    let mut calls = 0;
+    let (result, timing) = time(4, || {
+        calls += 1;
+        calls
+    });
+    assert_eq!((result, timing.runs()), (4, 4));
+}
@@ -0,0 +1,454 @@
+use anyhow::Context;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind},
+    queue,
+    style::{self, Print, ResetColor, SetForegroundColor},
+    terminal,
+};
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    time::Duration,
+};
+
+use crate::AocResult;
+
+/// A color a cell of a [`Frame`] can be drawn with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    Rgb(u8, u8, u8),
+}
+
+impl From<Color> for style::Color {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::White => Self::White,
+            Color::Grey => Self::DarkGrey,
+            Color::Rgb(r, g, b) => Self::Rgb { r, g, b },
+        }
+    }
+}
+
+/// One cell of a [`Frame`]
+pub type Cell = (char, Option<Color>);
+
+/// One picture of a simulation: a grid of characters that may be colored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    /// Line shown under the picture, like the step the simulation is at
+    pub caption: String,
+}
+
+impl Frame {
+    /// Creates a frame filled with `.`, the background of most puzzles
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, '.')
+    }
+
+    /// Creates a frame filled with spaces
+    #[must_use]
+    pub fn blank(width: usize, height: usize) -> Self {
+        Self::filled(width, height, ' ')
+    }
+
+    fn filled(width: usize, height: usize, c: char) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![(c, None); width * height],
+            caption: String::new(),
+        }
+    }
+
+    /// Creates a frame out of lines of text, padding the short ones with spaces
+    #[must_use]
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Self {
+        let width = lines
+            .iter()
+            .map(|l| l.as_ref().chars().count())
+            .max()
+            .unwrap_or_default();
+        let mut frame = Self::blank(width, lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.as_ref().chars().enumerate() {
+                frame.set(x, y, c);
+            }
+        }
+        frame
+    }
+
+    #[must_use]
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<Cell> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// Draws `c` in the default color, ignoring positions outside of the frame
+    pub fn set(&mut self, x: usize, y: usize, c: char) {
+        self.paint_cell(x, y, (c, None));
+    }
+
+    /// Draws `c` in `color`, ignoring positions outside of the frame
+    pub fn paint(&mut self, x: usize, y: usize, c: char, color: Color) {
+        self.paint_cell(x, y, (c, Some(color)));
+    }
+
+    fn paint_cell(&mut self, x: usize, y: usize, cell: Cell) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = cell;
+        }
+    }
+
+    /// Rows of cells from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Part of the frame that is `width` by `height` from `x`, `y` on
+    ///
+    /// The part is smaller when the frame ends before it does, and empty
+    /// when the frame ends before `x` or `y`.
+    #[must_use]
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        if x >= self.width || y >= self.height {
+            return Self::new(0, 0).with_caption(self.caption.clone());
+        }
+        let width = width.min(self.width.saturating_sub(x));
+        let height = height.min(self.height.saturating_sub(y));
+        let mut cropped = Self::new(width, height).with_caption(self.caption.clone());
+        for (row, cells) in self.rows().skip(y).take(height).enumerate() {
+            cropped.cells[row * width..(row + 1) * width].copy_from_slice(&cells[x..x + width]);
+        }
+        cropped
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self
+            .rows()
+            .map(|row| row.iter().map(|(c, _)| c).collect())
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// A simulation that can be watched one step at a time
+///
+/// Days opt in by implementing it on the state of their simulation and
+/// handing it out from [`Solution::visualize`](crate::Solution::visualize).
+pub trait Visualize {
+    /// Picture of the current state
+    fn frame(&self) -> Frame;
+
+    /// Advances the simulation by one step
+    ///
+    /// Returns `false`, leaving the state as it is, once there is nothing
+    /// left to simulate.
+    fn step(&mut self) -> bool;
+}
+
+/// Runs a simulation to its end, or for `limit` frames, collecting every frame
+pub fn frames(viz: &mut dyn Visualize, limit: usize) -> Vec<Frame> {
+    let mut frames = Vec::new();
+    if limit == 0 {
+        return frames;
+    }
+    frames.push(viz.frame());
+    while frames.len() < limit && viz.step() {
+        frames.push(viz.frame());
+    }
+    frames
+}
+
+/// Steps per second the player can go at
+const SPEEDS: [u32; 12] = [1, 2, 5, 10, 20, 30, 60, 120, 250, 500, 1_000, 5_000];
+
+/// Frames drawn per second at most, faster speeds skip frames
+const MAX_FPS: u32 = 60;
+
+/// What the player does after a key press
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Quit,
+    Step,
+    Redraw,
+    Ignore,
+}
+
+/// The state of the player the keys change
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Controls {
+    playing: bool,
+    speed: usize,
+    /// Top left corner of the part of the frame on screen
+    pan: (usize, usize),
+}
+
+impl Default for Controls {
+    fn default() -> Self {
+        Self {
+            playing: true,
+            speed: 3,
+            pan: (0, 0),
+        }
+    }
+}
+
+impl Controls {
+    const HELP: &'static str = "space play/pause, n step, +/- speed, arrows pan, q quit";
+
+    /// Acts on a key, panning no further than `limit`, the top left corner
+    /// that still fills the screen with the frame
+    fn handle(&mut self, key: KeyCode, limit: (usize, usize)) -> Command {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Command::Quit,
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Char('n') => {
+                self.playing = false;
+                return Command::Step;
+            }
+            KeyCode::Char('+' | '=') => self.speed = (self.speed + 1).min(SPEEDS.len() - 1),
+            KeyCode::Char('-') => self.speed = self.speed.saturating_sub(1),
+            KeyCode::Left => self.pan.0 = self.pan.0.saturating_sub(4).min(limit.0),
+            KeyCode::Right => self.pan.0 = (self.pan.0 + 4).min(limit.0),
+            KeyCode::Up => self.pan.1 = self.pan.1.saturating_sub(2).min(limit.1),
+            KeyCode::Down => self.pan.1 = (self.pan.1 + 2).min(limit.1),
+            _ => return Command::Ignore,
+        }
+        Command::Redraw
+    }
+
+    /// Time between two drawn frames and the steps taken in between
+    fn tick(&self) -> (Duration, usize) {
+        let speed = SPEEDS[self.speed];
+        let fps = speed.min(MAX_FPS);
+        let steps = speed.div_ceil(fps) as usize;
+        (Duration::from_secs(1) / fps, steps)
+    }
+
+    fn status(&self, step: usize, finished: bool) -> String {
+        let state = if finished {
+            "finished"
+        } else if self.playing {
+            "playing"
+        } else {
+            "paused"
+        };
+        format!(
+            "step {step} | {}/s | {state} | {}",
+            SPEEDS[self.speed],
+            Self::HELP
+        )
+    }
+}
+
+/// Puts the terminal back the way it was, even when the player fails
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        queue!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        io::stdout().flush()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = queue!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = io::stdout().flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Plays a simulation in the terminal until the viewer quits
+///
+/// Starts playing right away; the keys pause, step, change the speed and
+/// pan frames that don't fit on screen.
+///
+/// # Errors
+///
+/// Fails if the terminal can't be controlled, like when the output isn't one
+pub fn play(viz: &mut dyn Visualize) -> AocResult<()> {
+    let _terminal = RawTerminal::enter().context("Unable to take over the terminal")?;
+    let mut controls = Controls::default();
+    let mut frame = viz.frame();
+    let mut step = 0;
+    let mut finished = false;
+    loop {
+        draw(&frame, &controls, step, finished)?;
+        let (tick, steps) = controls.tick();
+        let waiting = !controls.playing || finished;
+        let timeout = if waiting {
+            Duration::from_secs(1)
+        } else {
+            tick
+        };
+        if event::poll(timeout)? {
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind == KeyEventKind::Release {
+                continue;
+            }
+            let (columns, rows) = viewport()?;
+            let limit = (
+                frame.width().saturating_sub(columns),
+                frame.height().saturating_sub(rows),
+            );
+            match controls.handle(key.code, limit) {
+                Command::Quit => break,
+                Command::Step if !finished => {
+                    finished = !advance(viz, &mut step, 1);
+                    frame = viz.frame();
+                }
+                _ => {}
+            }
+        } else if !waiting {
+            finished = !advance(viz, &mut step, steps);
+            frame = viz.frame();
+        }
+    }
+    Ok(())
+}
+
+/// Takes up to `steps` steps, returning `false` once the simulation is over
+fn advance(viz: &mut dyn Visualize, step: &mut usize, steps: usize) -> bool {
+    for _ in 0..steps {
+        if !viz.step() {
+            return false;
+        }
+        *step += 1;
+    }
+    true
+}
+
+/// Columns and rows of the screen left for frames above the status lines
+fn viewport() -> io::Result<(usize, usize)> {
+    let (columns, rows) = terminal::size()?;
+    Ok((usize::from(columns), usize::from(rows).saturating_sub(2)))
+}
+
+/// Draws the part of the frame that fits on screen, with the status lines under it
+fn draw(frame: &Frame, controls: &Controls, step: usize, finished: bool) -> io::Result<()> {
+    let (columns, rows) = viewport()?;
+    let (x, y) = controls.pan;
+    let visible = frame.crop(x, y, columns, rows);
+    let mut out = io::stdout().lock();
+    queue!(out, terminal::Clear(terminal::ClearType::All))?;
+    for (row, cells) in visible.rows().enumerate() {
+        queue!(out, cursor::MoveTo(0, row as u16))?;
+        let mut color = None;
+        for &(c, cell_color) in cells {
+            if cell_color != color {
+                match cell_color {
+                    Some(cell_color) => queue!(out, SetForegroundColor(cell_color.into()))?,
+                    None => queue!(out, ResetColor)?,
+                }
+                color = cell_color;
+            }
+            queue!(out, Print(c))?;
+        }
+        queue!(out, ResetColor)?;
+    }
+    let status_row = rows as u16;
+    let status = controls.status(step, finished);
+    for (i, line) in [frame.caption.as_str(), status.as_str()]
+        .into_iter()
+        .enumerate()
+    {
+        let line: String = line.chars().take(columns).collect();
+        queue!(out, cursor::MoveTo(0, status_row + i as u16), Print(line))?;
+    }
+    out.flush()
+}
+
+#[test]
+fn test_viz() {
+    struct Counter(usize);
+
+    impl Visualize for Counter {
+        fn frame(&self) -> Frame {
+            let mut frame = Frame::new(4, 2).with_caption(format!("step {}", self.0));
+            frame.paint(self.0, 1, '#', Color::Yellow);
+            frame
+        }
+
+        fn step(&mut self) -> bool {
+            if self.0 == 2 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+    }
+
+    let frames = frames(&mut Counter(0), 10);
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[2].to_string(), "....\n..#.");
+    assert_eq!(frames[2].get(2, 1), Some(('#', Some(Color::Yellow))));
+    assert_eq!(frames[2].get(4, 1), None);
+    assert_eq!(frames[2].crop(1, 1, 10, 10).to_string(), ".#.");
+    assert_eq!(frames[2].crop(4, 0, 2, 2).to_string(), "");
+    assert_eq!(frames[2].crop(0, 5, 2, 2).rows().count(), 0);
+    assert_eq!(frames[2].crop(9, 9, 2, 2).caption, "step 2");
+    assert_eq!(Frame::from_lines(&["ab", "c"]).to_string(), "ab\nc ");
+
+    let mut controls = Controls::default();
+    let limit = (6, 3);
+    assert_eq!(controls.handle(KeyCode::Char('n'), limit), Command::Step);
+    assert!(!controls.playing);
+    controls.handle(KeyCode::Left, limit);
+    controls.handle(KeyCode::Down, limit);
+    assert_eq!(controls.pan, (0, 2));
+    for _ in 0..3 {
+        controls.handle(KeyCode::Right, limit);
+        controls.handle(KeyCode::Down, limit);
+    }
+    assert_eq!(controls.pan, (6, 3));
+    controls.handle(KeyCode::Up, (2, 0));
+    assert_eq!(controls.pan, (6, 0));
+    for _ in 0..20 {
+        controls.handle(KeyCode::Char('+'), limit);
+    }
+    assert_eq!(controls.tick(), (Duration::from_secs(1) / 60, 84));
+    assert_eq!(controls.handle(KeyCode::Char('q'), limit), Command::Quit);
+}
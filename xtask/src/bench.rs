@@ -0,0 +1,125 @@
+use anyhow::Context;
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    time::SystemTime,
+};
+
+/// How much slower or faster a benchmark got compared to its baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Criterion's id of the benchmark, like `day-15/part2`
+    pub id: String,
+    /// Relative change of the mean time, `0.1` being 10% slower
+    pub mean: f64,
+    /// Bounds of the 95% confidence interval of `mean`
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl Change {
+    /// Only counts as a regression when the whole confidence interval
+    /// is past `threshold`, so noisy benchmarks don't fail the report
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.lower > threshold
+    }
+
+    pub fn improved(&self, threshold: f64) -> bool {
+        self.upper < -threshold
+    }
+}
+
+/// Reads the changes criterion wrote under `criterion_dir` since `start`
+///
+/// Criterion compares every benchmark with the previous run, or with the
+/// baseline given by `--baseline`, and keeps the last comparison around.
+/// Only comparisons newer than `start` belong to the run being reported.
+pub fn changes(criterion_dir: &Path, start: SystemTime) -> anyhow::Result<Vec<Change>> {
+    let mut changes = Vec::new();
+    if !criterion_dir.is_dir() {
+        return Ok(changes);
+    }
+    for group in fs::read_dir(criterion_dir)? {
+        let group = group?.path();
+        if !group.is_dir() {
+            continue;
+        }
+        for bench in fs::read_dir(&group)? {
+            let bench = bench?.path();
+            let estimates = bench.join("change").join("estimates.json");
+            let Ok(modified) = fs::metadata(&estimates).and_then(|m| m.modified()) else {
+                continue;
+            };
+            if modified < start {
+                continue;
+            }
+            let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&estimates)?)
+                .with_context(|| format!("Invalid estimates in {}", estimates.display()))?;
+            let mean = &json["mean"];
+            let number = |value: &serde_json::Value| {
+                value
+                    .as_f64()
+                    .with_context(|| format!("Missing mean in {}", estimates.display()))
+            };
+            let name = |path: &Path| {
+                path.file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string()
+            };
+            changes.push(Change {
+                id: format!("{}/{}", name(&group), name(&bench)),
+                mean: number(&mean["point_estimate"])?,
+                lower: number(&mean["confidence_interval"]["lower_bound"])?,
+                upper: number(&mean["confidence_interval"]["upper_bound"])?,
+            });
+        }
+    }
+    changes.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(changes)
+}
+
+/// Table of the changes, marking the ones past `threshold`
+pub struct Report<'a> {
+    pub changes: &'a [Change],
+    pub threshold: f64,
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .changes
+            .iter()
+            .map(|c| c.id.len())
+            .max()
+            .unwrap_or_default()
+            .max("Benchmark".len());
+        writeln!(
+            f,
+            "{:<width$}  {:>8}  {:>19}  Verdict",
+            "Benchmark", "Change", "95% interval"
+        )?;
+        for change in self.changes {
+            let verdict = if change.regressed(self.threshold) {
+                "regressed"
+            } else if change.improved(self.threshold) {
+                "improved"
+            } else {
+                "no change"
+            };
+            let interval = format!(
+                "{:+.1}% .. {:+.1}%",
+                change.lower * 100.0,
+                change.upper * 100.0
+            );
+            writeln!(
+                f,
+                "{:<width$}  {:>+7.1}%  {interval:>19}  {verdict}",
+                change.id,
+                change.mean * 100.0,
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -11,35 +11,91 @@ use std::{
 
 const AOC_YEAR: &str = "2022";
 
-const MAIN_SCAFFOLDING: &str = indoc! { r#"
+const LIB_SCAFFOLDING: &str = indoc! { r#"
 use itertools::Itertools;
+use utils::grid::Grid;
 #[allow(clippy::wildcard_imports)]
 use utils::*;
 
+#[cfg(feature = "gen")]
+mod generate;
+#[cfg(feature = "gen")]
+pub use generate::generate;
+
 #[derive(Debug, PartialEq)]
-struct InputData {}
+pub struct InputData {
+    pub grid: Grid<char>,
+}
 
-fn parse(input: &str) -> ParseResult<InputData> {
-    todo!()
+/// Reads the input as a map of characters, until it turns out to be
+/// something else
+///
+/// # Errors
+///
+/// Fails if the input is malformed
+pub fn parse(input: &str) -> ParseResult<'_, InputData> {
+    use nom::combinator::map;
+    use utils::parsers::char_grid;
+    map(char_grid(Some), |grid| InputData { grid })(input)
 }
 
 #[allow(clippy::unnecessary_wraps)]
-fn part1(input: &InputData) -> AocResult<()> {
+pub fn part1(input: &InputData) -> AocResult<()> {
     Ok(())
 }
 
 #[allow(clippy::unnecessary_wraps)]
-fn part2(input: &InputData) -> AocResult<()> {
+pub fn part2(input: &InputData) -> AocResult<()> {
     Ok(())
 }
 
-aoc_main!(parse, part1, part2);
+#[must_use]
+pub fn solution() -> impl Solution {
+    FnSolution::new({DAY-NUMBER}, parse, part1, part2)
+}
+
+aoc_examples!();
 
 #[test]
 fn test() {}
 
 "# };
 
+const MAIN_SCAFFOLDING: &str = indoc! { r#"
+utils::aoc_main!(day_{DAY-NUMBER}::solution());
+
+"# };
+
+const BUILD_SCAFFOLDING: &str = indoc! { r#"
+fn main() {
+    utils::examples::generate_tests();
+}
+
+"# };
+
+const GENERATE_SCAFFOLDING: &str = indoc! { r#"
+use utils::generate::StdRng;
+
+/// Writes an input of about `size` lines
+#[must_use]
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    todo!()
+}
+
+#[test]
+fn test_generate() {
+    utils::generate::check(&crate::solution(), generate);
+}
+
+"# };
+
+/// Answers of the example, commented out until they're known
+const EXAMPLE_ANSWERS_SCAFFOLDING: &str = indoc! { r#"
+# part1 = 0
+# part2 = 0
+
+"# };
+
 const CARGO_TOML_SCAFFOLDING: &str = indoc! { r#"
 [package]
 name = "day-{DAY-NUMBER}"
@@ -53,6 +109,13 @@ itertools = "0.10.5"
 nom = "7.1.1"
 utils = { path = "../utils", version = "*" }
 
+[build-dependencies]
+utils = { path = "../utils", version = "*" }
+
+[features]
+gen = ["utils/gen"]
+mem = ["utils/mem"]
+
 "# };
 
 /// Only create file if path doesn't exist
@@ -66,29 +129,36 @@ fn create_new<P: AsRef<Path>>(path: P) -> io::Result<File> {
 
 /// Generates the files for the new day
 ///
-/// Scaffolds the project files for the new day of Advent of Code.
-/// Then, we try to download the input file using the session key
-/// in the `~/.adventofcode` file.
+/// Scaffolds the project files for the new day of Advent of Code: a
+/// library with the solution, a thin binary running it, the example
+/// tests and an input generator. Then, we try to download the input file
+/// using the session key in the `~/.adventofcode` file.
 pub fn generate_day(day: u64, metadata: &Metadata) -> anyhow::Result<()> {
     let day_folder = format!("day-{day}");
     let location = metadata.workspace_root.as_std_path().join(day_folder);
     debug!("New folder location: {}", location.display());
     fs::create_dir_all(location.join("src"))?;
-    if let Ok(mut file) = create_new(location.join("Cargo.toml")) {
-        println!("Creating Cargo.toml");
-        file.write_all(
-            CARGO_TOML_SCAFFOLDING
-                .replace("{DAY-NUMBER}", &day.to_string())
-                .as_bytes(),
-        )?;
-    } else {
-        println!("Cargo.toml exists");
-    }
-    if let Ok(mut file) = create_new(location.join("src").join("main.rs")) {
-        println!("Creating main.rs");
-        file.write_all(MAIN_SCAFFOLDING.as_bytes())?;
-    } else {
-        println!("main.rs exists");
+    fs::create_dir_all(location.join("examples"))?;
+    let files = [
+        ("Cargo.toml", CARGO_TOML_SCAFFOLDING),
+        ("build.rs", BUILD_SCAFFOLDING),
+        ("src/lib.rs", LIB_SCAFFOLDING),
+        ("src/main.rs", MAIN_SCAFFOLDING),
+        ("src/generate.rs", GENERATE_SCAFFOLDING),
+        ("examples/1.txt", ""),
+        ("examples/1.toml", EXAMPLE_ANSWERS_SCAFFOLDING),
+    ];
+    for (name, scaffolding) in files {
+        if let Ok(mut file) = create_new(location.join(name)) {
+            println!("Creating {name}");
+            file.write_all(
+                scaffolding
+                    .replace("{DAY-NUMBER}", &day.to_string())
+                    .as_bytes(),
+            )?;
+        } else {
+            println!("{name} exists");
+        }
     }
     if location.join("input.txt").exists() {
         println!("input.txt exists");
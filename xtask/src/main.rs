@@ -1,9 +1,11 @@
+mod bench;
 mod create;
 
 use cargo_metadata::{Metadata, MetadataCommand};
 use clap::{builder::PossibleValue, Parser, ValueEnum};
 use log::trace;
-use std::fmt::Display;
+use std::{fmt::Display, path::PathBuf, time::SystemTime};
+use utils::solution::DaySet;
 use xshell::{cmd, Shell};
 
 use crate::create::{generate_day, generate_input};
@@ -12,6 +14,24 @@ use crate::create::{generate_day, generate_input};
 #[derive(Parser, Debug)]
 #[command(author, about, long_about = None)]
 enum Cli {
+    /// Benchmark the days and report how they changed since the last run
+    Bench {
+        /// Days to benchmark, like `3`, `1..=15` or `1,4..7`, every solved day by default
+        days: Option<String>,
+
+        /// Save the results as a baseline to compare with later
+        #[arg(long, value_name = "NAME", conflicts_with = "baseline")]
+        save_baseline: Option<String>,
+
+        /// Compare with a saved baseline instead of the last run
+        #[arg(long, value_name = "NAME")]
+        baseline: Option<String>,
+
+        /// Slowdown, in percent, past which a benchmark counts as regressed
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+    },
+
     /// Runs clippy on all projects
     Clippy,
 
@@ -33,6 +53,35 @@ enum Cli {
         part: SolutionPart,
     },
 
+    /// Write a random input for the day, bigger than the real one if asked
+    Gen {
+        /// The day to write an input for
+        #[arg(value_parser = clap::value_parser!(u64).range(1..=25))]
+        day: u64,
+
+        /// Seed of the random numbers, the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big the input is, in the day's own items like elves or sensors
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// File to write the input to instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Run the solutions for many days in one go and summarize them
+    Run {
+        /// Days to run, like `3`, `1..=15` or `1,4..7`, every solved day by default
+        days: Option<String>,
+
+        /// part of the task to do
+        #[arg(short, long, value_enum, default_value_t)]
+        part: SolutionPart,
+    },
+
     /// Print out a lovely christmas tree
     Tree,
 
@@ -45,6 +94,9 @@ enum Cli {
 
     /// Test all days
     TestAll,
+
+    /// Check the answers of every day that has an `answers.toml`
+    Verify,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -103,6 +155,14 @@ fn main() -> anyhow::Result<()> {
 
     let sh = Shell::new()?;
     match cli {
+        Cli::Bench {
+            days,
+            save_baseline,
+            baseline,
+            threshold,
+        } => {
+            bench(&sh, &metadata, days, save_baseline, baseline, threshold)?;
+        }
         Cli::Clippy => {
             cmd!(
                 sh,
@@ -130,6 +190,46 @@ fn main() -> anyhow::Result<()> {
             )
             .run()?;
         }
+        Cli::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let (day, seed, size) = (day.to_string(), seed.to_string(), size.to_string());
+            let mut gen = cmd!(
+                sh,
+                "cargo run -q --release --package aoc --features gen --bin aoc-gen -- {day} --seed {seed} --size {size}"
+            );
+            if let Some(path) = output {
+                gen = gen.arg("--output").arg(path);
+            }
+            gen.run()?;
+        }
+        Cli::Run { days, part } => {
+            let selected = match &days {
+                Some(days) => days.parse().map_err(anyhow::Error::msg)?,
+                None => DaySet::all(),
+            };
+            for package in day_packages(&metadata) {
+                let day = package.trim_start_matches("day-").parse()?;
+                let path = metadata
+                    .workspace_root
+                    .as_std_path()
+                    .join(&package)
+                    .join("input.txt");
+                if selected.contains(day) && !path.exists() {
+                    generate_input(day.into(), &path)?;
+                }
+            }
+            let root = metadata.workspace_root.as_std_path();
+            let part = format!("{part}");
+            cmd!(
+                sh,
+                "cargo run -q --release --package aoc -- {days...} --inputs {root} -p {part}"
+            )
+            .run()?;
+        }
         Cli::Tree => {
             cmd!(sh, "cargo run -q --release --package tree").run()?;
         }
@@ -140,21 +240,91 @@ fn main() -> anyhow::Result<()> {
         Cli::TestAll => {
             test_all(&sh, &metadata)?;
         }
+        Cli::Verify => {
+            let root = metadata.workspace_root.as_std_path();
+            let days: Vec<_> = day_packages(&metadata)
+                .into_iter()
+                .filter(|p| root.join(p).join("answers.toml").exists())
+                .map(|p| p.trim_start_matches("day-").to_string())
+                .collect();
+            if days.is_empty() {
+                println!("No day has an answers.toml to verify");
+                return Ok(());
+            }
+            let days = days.join(",");
+            cmd!(
+                sh,
+                "cargo run -q --release --package aoc -- {days} --check --inputs {root}"
+            )
+            .run()?;
+        }
     }
 
     Ok(())
 }
 
-/// Tests all of the Advent of Code projects in the workspace
-fn test_all(sh: &Shell, metadata: &Metadata) -> anyhow::Result<()> {
+/// Runs the criterion benchmarks of the `bench` package and reports the changes
+fn bench(
+    sh: &Shell,
+    metadata: &Metadata,
+    days: Option<String>,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+) -> anyhow::Result<()> {
+    let mut args = Vec::new();
+    if let Some(days) = days {
+        let days: DaySet = days.parse().map_err(anyhow::Error::msg)?;
+        let days: Vec<String> = days.iter().map(|d| d.to_string()).collect();
+        args.push(format!("^day-({})/", days.join("|")));
+    }
+    if let Some(name) = save_baseline {
+        args.extend(["--save-baseline".to_string(), name]);
+    }
+    if let Some(name) = baseline {
+        args.extend(["--baseline".to_string(), name]);
+    }
+    let start = SystemTime::now();
+    cmd!(
+        sh,
+        "cargo bench -q --package bench --bench days -- {args...}"
+    )
+    .run()?;
+
+    let criterion_dir = metadata.target_directory.as_std_path().join("criterion");
+    let changes = bench::changes(&criterion_dir, start)?;
+    if changes.is_empty() {
+        println!("Nothing to compare with yet, run again to see the changes");
+        return Ok(());
+    }
+    let threshold = threshold / 100.0;
+    println!(
+        "\n{}",
+        bench::Report {
+            changes: &changes,
+            threshold
+        }
+    );
+    let regressed = changes.iter().filter(|c| c.regressed(threshold)).count();
+    anyhow::ensure!(regressed == 0, "{regressed} benchmarks regressed");
+    Ok(())
+}
+
+/// Names of the Advent of Code projects in the workspace
+fn day_packages(metadata: &Metadata) -> Vec<String> {
     metadata
         .workspace_packages()
         .iter()
         .filter(|p| p.name.starts_with("day"))
-        .try_for_each(|p| {
-            let name = p.name.clone();
-            cmd!(sh, "cargo test -q -p {name}")
-                .run()
-                .map_err(anyhow::Error::from)
-        })
+        .map(|p| p.name.clone())
+        .collect()
+}
+
+/// Tests all of the Advent of Code projects in the workspace
+fn test_all(sh: &Shell, metadata: &Metadata) -> anyhow::Result<()> {
+    day_packages(metadata).into_iter().try_for_each(|name| {
+        cmd!(sh, "cargo test -q -p {name}")
+            .run()
+            .map_err(anyhow::Error::from)
+    })
 }